@@ -1,20 +1,45 @@
-use crate::models::activity::{CodingStatus, DailySummary};
+use crate::models::activity::{CodingStatus, DailySummary, ProjectSummary};
+use crate::services::activity::ActivityState;
+use crate::services::{git, storage};
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
 
 /// 오늘 활동 요약
 #[tauri::command]
-pub async fn get_today_summary() -> Result<DailySummary, String> {
-    // TODO: storage에서 오늘 데이터 로드
-    Ok(DailySummary::default())
+pub async fn get_today_summary(app: AppHandle) -> Result<DailySummary, String> {
+    let mut data = storage::load(&app)?;
+    storage::roll_over_day(&mut data);
+    Ok(data.today)
 }
 
 /// 현재 코딩 상태
 #[tauri::command]
-pub async fn get_coding_status() -> Result<CodingStatus, String> {
-    // TODO: activity monitor에서 현재 상태
-    Ok(CodingStatus {
-        is_coding: false,
-        active_ide: None,
-        idle_seconds: 0,
-        session_minutes: 0,
-    })
+pub async fn get_coding_status(state: State<'_, ActivityState>) -> Result<CodingStatus, String> {
+    let status = state.0.lock().map_err(|e| e.to_string())?;
+    Ok(status.clone())
+}
+
+/// 저장소별 오늘 코딩 시간 + 커밋 수
+#[tauri::command]
+pub async fn get_project_summaries(app: AppHandle) -> Result<Vec<ProjectSummary>, String> {
+    let mut data = storage::load(&app)?;
+    storage::roll_over_day(&mut data);
+
+    Ok(data
+        .settings
+        .git_repos
+        .iter()
+        .map(|repo| {
+            let path = PathBuf::from(repo);
+            ProjectSummary {
+                path: repo.clone(),
+                name: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| repo.clone()),
+                coding_minutes: data.today.project_minutes.get(repo).copied().unwrap_or(0),
+                commits: git::count_today_commits(&path),
+            }
+        })
+        .collect())
 }
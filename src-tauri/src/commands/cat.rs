@@ -1,22 +1,144 @@
-use crate::models::cat::CatInfo;
+use crate::events::event_names;
+use crate::models::cat::{CatInfo, CatReaction, CatSummary, ClickKind};
+use crate::models::cosmetic::{CosmeticSlot, Inventory};
+use crate::models::growth::exp_for_level;
+use crate::services::cat_state::{self, CatStateHandle};
+use crate::services::cats;
+use crate::services::cosmetics;
+use crate::services::interaction;
+use crate::services::mood::{self, MoodWindow};
+use crate::services::needs;
+use crate::services::state_machine::StateMachine;
+use crate::services::storage;
+use tauri::{AppHandle, Emitter, State};
 
 /// 고양이 현재 상태 조회
 #[tauri::command]
-pub async fn get_cat_state() -> Result<CatInfo, String> {
-    // TODO: 실제 상태 머신에서 현재 상태 가져오기
+pub async fn get_cat_state(
+    app: AppHandle,
+    cat_state: State<'_, CatStateHandle>,
+    machine: State<'_, StateMachine>,
+) -> Result<CatInfo, String> {
+    let data = storage::load(&app)?;
+    let state = cat_state.current();
+    let now = chrono::Local::now();
+    let needs = needs::current(&data.cat.needs, now.timestamp());
+    let window = MoodWindow::collect(&data, now.date_naive(), now.timestamp());
+    let (mood, mood_factors) = mood::derive(machine.mood(&state), &needs, &window);
+    let (id, name) = cats::active(&data).map_or_else(Default::default, |cat| (cat.id.clone(), cat.name.clone()));
+    let cat = data.cat;
     Ok(CatInfo {
-        state: crate::models::cat::CatState::Idle,
-        mood: crate::models::cat::CatMood::Happy,
-        level: 1,
-        exp: 0,
-        exp_to_next: 60,
-        streak_days: 0,
+        id,
+        name,
+        mood,
+        state,
+        level: cat.level,
+        exp: cat.exp,
+        exp_to_next: exp_for_level(cat.level),
+        streak_days: cat.streak_days,
+        evolution: cat.evolution,
+        affection: cat.affection,
+        look: cat.equipped,
+        needs,
+        mood_factors,
     })
 }
 
-/// 고양이 클릭 인터랙션
+/// 고양이 클릭 인터랙션 (kind 생략 시 한 번 클릭)
 #[tauri::command]
-pub async fn click_cat() -> Result<String, String> {
-    // TODO: 상태를 Interaction으로 전환, 반응 애니메이션 트리거
-    Ok("meow!".to_string())
+pub async fn click_cat(app: AppHandle, kind: Option<ClickKind>) -> Result<CatReaction, String> {
+    interaction::touch(&app, kind.unwrap_or_default())
+}
+
+/// 꾸미기 인벤토리 (조건을 새로 만족한 아이템은 이때도 해금)
+#[tauri::command]
+pub async fn get_inventory(app: AppHandle) -> Result<Inventory, String> {
+    let (inventory, new_items) = storage::update(&app, |data| {
+        let new_items = cosmetics::unlock_new(data, chrono::Local::now().timestamp());
+        (cosmetics::inventory(&data.cat), new_items)
+    })?;
+    cosmetics::emit_unlocked(&app, &new_items);
+    Ok(inventory)
+}
+
+/// 아이템 착용 (itemId가 없으면 그 자리 벗기)
+#[tauri::command]
+pub async fn equip_item(
+    app: AppHandle,
+    slot: CosmeticSlot,
+    item_id: Option<String>,
+) -> Result<Inventory, String> {
+    let inventory = storage::update(&app, |data| {
+        cosmetics::equip(&mut data.cat, slot, item_id.as_deref())?;
+        Ok::<_, String>(cosmetics::inventory(&data.cat))
+    })??;
+    let _ = app.emit(event_names::CAT_LOOK_CHANGED, &inventory.equipped);
+    Ok(inventory)
+}
+
+/// 고양이 목록 (은퇴한 고양이 포함)
+#[tauri::command]
+pub async fn list_cats(app: AppHandle) -> Result<Vec<CatSummary>, String> {
+    Ok(cats::summaries(&storage::load(&app)?))
+}
+
+/// 새 고양이 (repos: 이 경로 아래 저장소 활동을 받음, hours: [시작, 끝) 시각 활동을 받음)
+#[tauri::command]
+pub async fn create_cat(
+    app: AppHandle,
+    name: String,
+    repos: Option<Vec<String>>,
+    hours: Option<(u32, u32)>,
+) -> Result<CatSummary, String> {
+    let now = chrono::Local::now().timestamp();
+    let (created, summaries) = storage::update(&app, |data| {
+        let created = cats::create(data, &name, repos.unwrap_or_default(), hours, now)?;
+        Ok::<_, String>((created, cats::summaries(data)))
+    })??;
+    let _ = app.emit(event_names::CAT_PROFILES_CHANGED, &summaries);
+    Ok(created)
+}
+
+/// 이름 바꾸기
+#[tauri::command]
+pub async fn rename_cat(app: AppHandle, id: String, name: String) -> Result<Vec<CatSummary>, String> {
+    let summaries = storage::update(&app, |data| {
+        cats::rename(data, &id, &name)?;
+        Ok::<_, String>(cats::summaries(data))
+    })??;
+    let _ = app.emit(event_names::CAT_PROFILES_CHANGED, &summaries);
+    Ok(summaries)
+}
+
+/// 화면에 보일 고양이 바꾸기
+#[tauri::command]
+pub async fn switch_cat(app: AppHandle, id: String) -> Result<Vec<CatSummary>, String> {
+    let summaries = storage::update(&app, |data| {
+        cats::switch(data, &id)?;
+        Ok::<_, String>(cats::summaries(data))
+    })??;
+    emit_switched(&app, &summaries)?;
+    Ok(summaries)
+}
+
+/// 은퇴 (기록은 남고 EXP는 더 받지 않음, 선택된 고양이면 다른 고양이로 바뀜)
+#[tauri::command]
+pub async fn retire_cat(app: AppHandle, id: String) -> Result<Vec<CatSummary>, String> {
+    let now = chrono::Local::now().timestamp();
+    let summaries = storage::update(&app, |data| {
+        cats::retire(data, &id, now)?;
+        Ok::<_, String>(cats::summaries(data))
+    })??;
+    emit_switched(&app, &summaries)?;
+    Ok(summaries)
+}
+
+/// 목록 + 새로 선택된 고양이의 상태 / 모습 / 욕구 알림
+fn emit_switched(app: &AppHandle, summaries: &[CatSummary]) -> Result<(), String> {
+    let cat = storage::load(app)?.cat;
+    cat_state::reload(app, &cat.state);
+    let _ = app.emit(event_names::CAT_PROFILES_CHANGED, summaries);
+    let _ = app.emit(event_names::CAT_LOOK_CHANGED, &cat.equipped);
+    needs::emit_changed(app, &needs::current(&cat.needs, chrono::Local::now().timestamp()));
+    Ok(())
 }
@@ -0,0 +1,15 @@
+use crate::models::dnd::DndStatus;
+use crate::services::dnd;
+use tauri::AppHandle;
+
+/// 방해 금지 상태 (이유 + 모아 둔 알림 수)
+#[tauri::command]
+pub async fn get_dnd_status(app: AppHandle) -> Result<DndStatus, String> {
+    Ok(dnd::status(&app))
+}
+
+/// 방해 금지 직접 켜기 / 끄기 (minutes가 있으면 그 시간 동안만)
+#[tauri::command]
+pub async fn set_dnd(app: AppHandle, enabled: bool, minutes: Option<u32>) -> Result<DndStatus, String> {
+    Ok(dnd::set_manual(&app, enabled, minutes))
+}
@@ -1,7 +1,7 @@
+use crate::services::ide_detect;
+
+/// 포커스된 창이 풀스크린인지 (지금은 Linux X11만 감지, 그 외는 false)
 #[tauri::command]
 pub async fn check_fullscreen() -> Result<bool, String> {
-    // TODO: OS별 풀스크린 감지
-    // macOS: CGDisplayCapture / NSApplication
-    // Windows: foreground window size vs screen size
-    Ok(false)
+    Ok(ide_detect::detect_fullscreen())
 }
@@ -1,18 +1,44 @@
+use crate::services::settings::SettingsState;
+use crate::services::{git, storage};
+use std::path::PathBuf;
+use tauri::{AppHandle, State};
+
+/// 등록된 저장소들의 오늘 커밋 수 합계
 #[tauri::command]
-pub async fn get_today_commits() -> Result<u32, String> {
-    // TODO: 등록된 repo들에서 오늘 커밋 수 합산
-    Ok(0)
+pub async fn get_today_commits(state: State<'_, SettingsState>) -> Result<u32, String> {
+    Ok(state
+        .current()
+        .git_repos
+        .iter()
+        .map(|repo| git::count_today_commits(&PathBuf::from(repo)))
+        .sum())
 }
 
 #[tauri::command]
-pub async fn register_repo(path: String) -> Result<bool, String> {
-    // TODO: git repo 경로 등록 → settings에 저장
+pub async fn register_repo(
+    app: AppHandle,
+    state: State<'_, SettingsState>,
+    path: String,
+) -> Result<bool, String> {
     // 유효한 git repo인지 확인 (.git 디렉토리 존재)
     let git_dir = std::path::Path::new(&path).join(".git");
-    if git_dir.exists() {
-        // TODO: settings.git_repos에 추가
-        Ok(true)
-    } else {
-        Err("Not a valid git repository".to_string())
+    if !git_dir.exists() {
+        return Err("Not a valid git repository".to_string());
     }
+
+    // 프로젝트 판정 시 경로 비교가 가능하도록 정규화해서 저장
+    let repo = std::fs::canonicalize(&path)
+        .map_err(|e| format!("Failed to resolve repository path: {}", e))?
+        .to_string_lossy()
+        .to_string();
+
+    let settings = storage::update(&app, |data| {
+        if !data.settings.git_repos.contains(&repo) {
+            data.settings.git_repos.push(repo);
+        }
+        data.settings.clone()
+    })?;
+
+    state.replace(settings);
+    Ok(true)
 }
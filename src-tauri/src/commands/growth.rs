@@ -1,24 +1,33 @@
-use crate::models::growth::LevelInfo;
+use crate::models::achievement::Achievement;
+use crate::models::growth::{exp_for_level, ExpBreakdown, LevelInfo};
+use crate::services::achievements;
+use crate::services::growth;
+use crate::services::storage;
+use tauri::AppHandle;
 
 #[tauri::command]
-pub async fn get_level_info() -> Result<LevelInfo, String> {
-    // TODO: storage에서 레벨 데이터 로드
+pub async fn get_level_info(app: AppHandle) -> Result<LevelInfo, String> {
+    let cat = storage::load(&app)?.cat;
     Ok(LevelInfo {
-        level: 1,
-        current_exp: 0,
-        exp_to_next: 60,
-        total_exp: 0,
+        level: cat.level,
+        current_exp: cat.exp,
+        exp_to_next: exp_for_level(cat.level),
+        total_exp: cat.total_exp,
     })
 }
 
+/// 오늘 EXP 출처별 내역
 #[tauri::command]
-pub async fn get_exp_breakdown() -> Result<serde_json::Value, String> {
-    // TODO: 오늘 EXP 소스별 내역
-    Ok(serde_json::json!({
-        "coding": 0,
-        "commits": 0,
-        "pomodoro": 0,
-        "streak": 0,
-        "total": 0
-    }))
+pub async fn get_exp_breakdown(app: AppHandle) -> Result<ExpBreakdown, String> {
+    let mut data = storage::load(&app)?;
+    storage::roll_over_day(&mut data);
+    Ok(growth::exp_breakdown(&data.today))
+}
+
+/// 전체 업적 (해금 시각 + 진행도)
+#[tauri::command]
+pub async fn get_achievements(app: AppHandle) -> Result<Vec<Achievement>, String> {
+    let mut data = storage::load(&app)?;
+    storage::roll_over_day(&mut data);
+    Ok(achievements::list(&data, chrono::Local::now().date_naive()))
 }
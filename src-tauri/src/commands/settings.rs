@@ -1,12 +1,53 @@
-use crate::models::settings::AppSettings;
+use crate::models::ide::IdeRule;
+use crate::models::settings::{valid_hours, AppSettings};
+use crate::services::ide_rules::{IdeRuleSet, IdeRulesState};
+use crate::services::settings::SettingsState;
+use crate::services::storage;
+use tauri::{AppHandle, State};
 
 #[tauri::command]
-pub async fn get_settings() -> Result<AppSettings, String> {
-    Ok(AppSettings::default())
+pub async fn get_settings(state: State<'_, SettingsState>) -> Result<AppSettings, String> {
+    Ok(state.current())
 }
 
 #[tauri::command]
-pub async fn update_settings(_settings: AppSettings) -> Result<bool, String> {
-    // TODO: 설정 저장
+pub async fn update_settings(
+    app: AppHandle,
+    state: State<'_, SettingsState>,
+    settings: AppSettings,
+) -> Result<bool, String> {
+    if let Some(hours) = settings.quiet_hours {
+        valid_hours(hours).map_err(|e| format!("Quiet hours: {}", e))?;
+    }
+
+    // 디스크에 먼저 저장한 뒤 실행 중인 서비스에 반영
+    // git_repos는 register_repo가 관리하므로 프론트엔드 값(오래됐을 수 있음)은 무시
+    let settings = storage::update(&app, |data| {
+        let git_repos = std::mem::take(&mut data.settings.git_repos);
+        data.settings = AppSettings { git_repos, ..settings };
+        data.settings.clone()
+    })?;
+
+    state.replace(settings);
+    Ok(true)
+}
+
+/// IDE 감지 규칙 조회 (모든 OS 규칙 포함)
+#[tauri::command]
+pub async fn get_ide_rules(state: State<'_, IdeRulesState>) -> Result<Vec<IdeRule>, String> {
+    Ok(state.current().rules())
+}
+
+/// IDE 감지 규칙 교체 (검증 실패 시 저장하지 않음)
+#[tauri::command]
+pub async fn update_ide_rules(
+    app: AppHandle,
+    state: State<'_, IdeRulesState>,
+    rules: Vec<IdeRule>,
+) -> Result<bool, String> {
+    let compiled = IdeRuleSet::compile(rules.clone())?;
+    storage::save_ide_rules(&app, &rules)?;
+
+    state.replace(compiled);
     Ok(true)
 }
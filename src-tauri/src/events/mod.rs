@@ -6,15 +6,35 @@ pub mod event_names {
     pub const CAT_STATE_CHANGED: &str = "cat:state-changed";
     pub const CAT_LEVEL_UP: &str = "cat:level-up";
     pub const CAT_EXP_GAINED: &str = "cat:exp-gained";
+    pub const CAT_EVOLVED: &str = "cat:evolved";
+    pub const CAT_NEEDS_CHANGED: &str = "cat:needs-changed";
+    pub const CAT_REACTION: &str = "cat:reaction";
+    pub const CAT_ACHIEVEMENT_UNLOCKED: &str = "cat:achievement-unlocked";
+    pub const CAT_ITEM_UNLOCKED: &str = "cat:item-unlocked";
+    pub const CAT_LOOK_CHANGED: &str = "cat:look-changed";
+    pub const CAT_PROFILES_CHANGED: &str = "cat:profiles-changed";
 
     // 활동 감지
     pub const ACTIVITY_IDE_DETECTED: &str = "activity:ide-detected";
+    pub const ACTIVITY_IDE_CLOSED: &str = "activity:ide-closed";
     pub const ACTIVITY_IDLE: &str = "activity:idle";
+    pub const ACTIVITY_SLEEPING: &str = "activity:sleeping";
+    pub const ACTIVITY_LATE_NIGHT_CODING: &str = "activity:late-night-coding";
+    pub const ACTIVITY_STATUS: &str = "activity:status";
     pub const ACTIVITY_FULLSCREEN: &str = "activity:fullscreen";
 
+    // 쉬어 가기
+    pub const WELLBEING_REMINDER: &str = "wellbeing:reminder";
+
+    // 방해 금지
+    pub const DND_CHANGED: &str = "dnd:changed";
+
     // Git
     pub const GIT_NEW_COMMIT: &str = "git:new-commit";
 
+    // 빌드 / 테스트
+    pub const BUILD_RESULT: &str = "build:result";
+
     // 뽀모도로
     pub const POMODORO_TICK: &str = "pomodoro:tick";
     pub const POMODORO_COMPLETE: &str = "pomodoro:complete";
@@ -28,6 +28,11 @@ fn setup_macos_window(window: &tauri::WebviewWindow) {
     }
 }
 
+/// `commit-cat notify ...`: 실행 중인 앱에 빌드 / 테스트 결과 전달 (GUI는 띄우지 않음)
+pub fn notify_cli(args: &[String]) -> i32 {
+    services::build_signal::notify_cli(args)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -41,6 +46,40 @@ pub fn run() {
 
             // Initialize local data storage
             services::storage::init(&app_handle)?;
+            let data = services::storage::load(&app_handle)?;
+
+            // 설정은 실행 중 공유 상태로 관리 (update_settings → 백그라운드 서비스 반영)
+            app.manage(services::settings::SettingsState::new(data.settings));
+            app.manage(services::activity::ActivityState::default());
+            app.manage(services::pomodoro::PomodoroState::default());
+            app.manage(services::build_signal::BuildState::default());
+            app.manage(services::interaction::InteractionState::default());
+            app.manage(services::wellbeing::WellbeingState::default());
+            app.manage(services::dnd::DndState::default());
+
+            // 고양이 행동 정의 (파일이 잘못되었으면 내장 행동으로 시작)
+            let behavior = services::storage::load_behavior(&app_handle)
+                .and_then(services::state_machine::StateMachine::compile)
+                .unwrap_or_else(|e| {
+                    eprintln!("Using built-in cat behavior: {}", e);
+                    services::state_machine::StateMachine::builtin()
+                });
+
+            // 고양이 상태 머신 (마지막 상태에서 이어서)
+            let (cat_state, state_rx) =
+                services::cat_state::CatStateHandle::new(behavior.restore(&data.cat.state));
+            cat_state.set_progress(&data.cat);
+            app.manage(cat_state.clone());
+            app.manage(behavior);
+
+            // IDE 감지 규칙 (파일이 깨져 있으면 내장 규칙으로 시작)
+            let ide_rules = services::storage::load_ide_rules(&app_handle)
+                .and_then(services::ide_rules::IdeRuleSet::compile)
+                .unwrap_or_else(|e| {
+                    eprintln!("Using built-in IDE rules: {}", e);
+                    services::ide_rules::IdeRuleSet::builtin()
+                });
+            app.manage(services::ide_rules::IdeRulesState::new(ide_rules));
 
             // macOS 투명 윈도우 설정
             #[cfg(target_os = "macos")]
@@ -48,12 +87,43 @@ pub fn run() {
                 setup_macos_window(&window);
             }
 
+            // 상태 머신 러너 시작
+            let runner_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                services::cat_state::start_runner(runner_handle, cat_state, state_rx).await;
+            });
+
             // 활동 모니터 시작
             let monitor_handle = app_handle.clone();
             tauri::async_runtime::spawn(async move {
                 services::activity::start_monitor(monitor_handle).await;
             });
 
+            // Git 커밋 감시 시작
+            let git_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                services::git::start_watcher(git_handle).await;
+            });
+
+            // 방해 금지: 조용한 시간 / 화면 공유 확인
+            let dnd_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                services::dnd::start_watcher(dnd_handle).await;
+            });
+
+            // 빌드 / 테스트 결과 수신 (notify 소켓 + JUnit 리포트)
+            #[cfg(unix)]
+            {
+                let listener_handle = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    services::build_signal::start_listener(listener_handle).await;
+                });
+            }
+            let junit_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                services::build_signal::start_junit_watcher(junit_handle).await;
+            });
+
             Ok(())
         })
         // ── Commands (frontend ↔ backend) ──
@@ -61,12 +131,21 @@ pub fn run() {
             // Cat state
             commands::cat::get_cat_state,
             commands::cat::click_cat,
+            commands::cat::get_inventory,
+            commands::cat::equip_item,
+            commands::cat::list_cats,
+            commands::cat::create_cat,
+            commands::cat::rename_cat,
+            commands::cat::switch_cat,
+            commands::cat::retire_cat,
             // Activity
             commands::activity::get_today_summary,
             commands::activity::get_coding_status,
+            commands::activity::get_project_summaries,
             // Growth
             commands::growth::get_level_info,
             commands::growth::get_exp_breakdown,
+            commands::growth::get_achievements,
             // Pomodoro
             commands::pomodoro::start_pomodoro,
             commands::pomodoro::stop_pomodoro,
@@ -77,8 +156,13 @@ pub fn run() {
             // Settings
             commands::settings::get_settings,
             commands::settings::update_settings,
+            commands::settings::get_ide_rules,
+            commands::settings::update_ide_rules,
             // Fullscreen
             commands::fullscreen::check_fullscreen,
+            // Do not disturb
+            commands::dnd::get_dnd_status,
+            commands::dnd::set_dnd,
         ])
         // ── Tray icon click handler ──
         .on_tray_icon_event(|tray, event| {
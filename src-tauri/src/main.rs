@@ -2,5 +2,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // `commit-cat notify ...` → 빌드 결과만 전달하고 종료
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("notify") {
+        std::process::exit(commit_cat_lib::notify_cli(&args[1..]));
+    }
     commit_cat_lib::run()
 }
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+
+/// 업적 판정에 쓰는 누적 기록
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AchievementMetric {
+    TotalCommits,
+    StreakDays,
+    TotalPomodoros,
+    /// 누적 코딩 시간 (시간)
+    CodingHours,
+    /// 오늘 커밋한 저장소 수
+    ReposCommittedToday,
+    /// 코딩한 주 다음 주말을 통째로 쉬면 1
+    WeekendOff,
+}
+
+/// 업적 정의
+#[derive(Debug, Clone, Copy)]
+pub struct AchievementRule {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub icon: &'static str,
+    pub metric: AchievementMetric,
+    /// 이 값에 닿으면 해금
+    pub goal: u32,
+}
+
+/// 업적 목록 (id는 저장 키이므로 바꾸지 않는다)
+pub const ACHIEVEMENTS: &[AchievementRule] = &[
+    AchievementRule {
+        id: "first-commit",
+        name: "First Commit",
+        description: "Make your first commit with the cat watching",
+        icon: "🌱",
+        metric: AchievementMetric::TotalCommits,
+        goal: 1,
+    },
+    AchievementRule {
+        id: "commits-100",
+        name: "Centurion",
+        description: "Make 100 commits",
+        icon: "💯",
+        metric: AchievementMetric::TotalCommits,
+        goal: 100,
+    },
+    AchievementRule {
+        id: "streak-7",
+        name: "One Week Streak",
+        description: "Code 7 days in a row",
+        icon: "🔥",
+        metric: AchievementMetric::StreakDays,
+        goal: 7,
+    },
+    AchievementRule {
+        id: "streak-30",
+        name: "Habit Formed",
+        description: "Code 30 days in a row",
+        icon: "📅",
+        metric: AchievementMetric::StreakDays,
+        goal: 30,
+    },
+    AchievementRule {
+        id: "pomodoro-1",
+        name: "Tomato Sprout",
+        description: "Finish your first pomodoro",
+        icon: "🍅",
+        metric: AchievementMetric::TotalPomodoros,
+        goal: 1,
+    },
+    AchievementRule {
+        id: "pomodoro-100",
+        name: "Tomato Farmer",
+        description: "Finish 100 pomodoros",
+        icon: "🧺",
+        metric: AchievementMetric::TotalPomodoros,
+        goal: 100,
+    },
+    AchievementRule {
+        id: "coding-100h",
+        name: "Hundred Hours",
+        description: "Code for 100 hours",
+        icon: "⌨️",
+        metric: AchievementMetric::CodingHours,
+        goal: 100,
+    },
+    AchievementRule {
+        id: "coding-1000h",
+        name: "Thousand Hours",
+        description: "Code for 1000 hours",
+        icon: "🏆",
+        metric: AchievementMetric::CodingHours,
+        goal: 1000,
+    },
+    AchievementRule {
+        id: "five-repos-one-day",
+        name: "Juggler",
+        description: "Commit to 5 different repositories in one day",
+        icon: "🤹",
+        metric: AchievementMetric::ReposCommittedToday,
+        goal: 5,
+    },
+    AchievementRule {
+        id: "weekend-off",
+        name: "Touch Grass",
+        description: "Take a whole weekend off after a week of coding",
+        icon: "🌳",
+        metric: AchievementMetric::WeekendOff,
+        goal: 1,
+    },
+];
+
+/// 프론트엔드로 전달하는 업적 (`cat:achievement-unlocked` 내용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Achievement {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub icon: String,
+    pub progress: u32,
+    pub goal: u32,
+    /// 해금 시각 (unix 초)
+    pub unlocked_at: Option<i64>,
+}
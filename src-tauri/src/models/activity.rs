@@ -1,24 +1,66 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// 오늘 하루 요약
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct DailySummary {
     pub date: String,                // "2026-02-22"
     pub coding_minutes: u32,
     pub commits: u32,
+    /// 저장소 경로 → 커밋 수
+    pub repo_commits: BTreeMap<String, u32>,
     pub pomodoro_sessions: u32,
+    /// 끝까지 마치지 못하고 중지한 뽀모도로 수
+    pub pomodoro_cancelled: u32,
     pub exp_gained: u32,
+    /// 밤 시간대(설정)에 코딩한 시간 (분)
+    pub late_night_minutes: u32,
+    /// 받은 빌드 / 테스트 결과 수와 그중 실패 수
+    pub builds: u32,
+    pub build_failures: u32,
+    /// 저장소 경로 → 코딩 시간 (분)
+    pub project_minutes: BTreeMap<String, u32>,
+    /// 언어 → 코딩 시간 (분)
+    pub language_minutes: BTreeMap<String, u32>,
 }
 
 /// 실시간 코딩 상태
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CodingStatus {
     pub is_coding: bool,
     pub active_ide: Option<String>,  // "VS Code" | "IntelliJ" | null
     pub idle_seconds: u64,
     pub session_minutes: u32,
+    /// 작업 중인 등록 저장소 경로
+    pub project: Option<String>,
+    /// 작업 중인 언어 (예: "Rust")
+    pub language: Option<String>,
+    /// 실행 중인 모든 IDE / 에디터
+    pub running_ides: Vec<String>,
+}
+
+/// 코딩 세션 저장 상태
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SessionSnapshot {
+    /// 현재 세션 누적 코딩 시간 (초)
+    pub session_seconds: u64,
+    /// 아직 분 단위로 적립되지 않은 코딩 시간 (초, 60 미만)
+    pub pending_seconds: u64,
+    /// 마지막 코딩 시각 (unix timestamp)
+    pub last_active_at: Option<i64>,
+}
+
+/// 저장소별 오늘 활동 (프로젝트 리포트)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSummary {
+    pub path: String,
+    pub name: String,
+    pub coding_minutes: u32,
+    pub commits: u32,
 }
 
 /// 뽀모도로 상태
@@ -30,3 +72,36 @@ pub struct PomodoroStatus {
     pub total_seconds: u32,
     pub sessions_today: u32,
 }
+
+/// 빌드 / 테스트 결과 출처
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BuildSource {
+    /// `commit-cat notify` (셸 훅, cargo / npm 래퍼)
+    Cli,
+    /// JUnit XML 리포트 파일
+    Junit,
+}
+
+/// 빌드 / 테스트 결과 (`build:result` 이벤트, notify 소켓 메시지)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildResult {
+    pub success: bool,
+    pub source: BuildSource,
+    /// 프로세스 종료 코드 (CLI)
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+    /// 실패한 테스트 수 (JUnit)
+    #[serde(default)]
+    pub failures: Option<u32>,
+    /// 실행한 커맨드 (예: "cargo test")
+    #[serde(default)]
+    pub command: Option<String>,
+    /// 작업 디렉토리 / 저장소 경로
+    #[serde(default)]
+    pub project: Option<String>,
+    /// 직전 실패를 고친 빌드인지 (앱이 채움)
+    #[serde(default)]
+    pub fixed: bool,
+}
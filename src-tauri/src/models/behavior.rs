@@ -0,0 +1,216 @@
+use super::cat::{CatMood, ClickKind};
+use serde::{Deserialize, Serialize};
+
+/// 고양이 행동 정의 (cat-behavior.json)
+///
+/// 상태와 전환 규칙을 파일로 정의해 새 행동을 코드 수정 없이 추가할 수 있다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BehaviorSpec {
+    /// 시작 상태 (처음 실행 / 저장된 상태를 복원할 수 없을 때)
+    pub initial: String,
+    pub states: Vec<StateSpec>,
+    /// 전환 규칙 — 위에서부터 비교하며 처음 일치한 규칙이 적용된다
+    pub transitions: Vec<TransitionSpec>,
+}
+
+/// 상태 하나
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StateSpec {
+    /// 상태 이름 (프론트엔드 애니메이션 이름과 같음)
+    pub name: String,
+    pub mood: CatMood,
+    /// 임시 상태: 이 시간(초)이 지나면 `timerExpired` 발생
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+}
+
+/// 전환 규칙 하나
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitionSpec {
+    /// 출발 상태들 ("*" = 모든 상태)
+    pub from: Vec<String>,
+    pub on: EventKind,
+    /// 도착 상태 (null이면 이벤트를 흡수하고 전환하지 않음 → 아래 규칙도 보지 않는다)
+    pub to: Option<String>,
+    #[serde(default)]
+    pub guard: Guard,
+}
+
+/// 규칙이 반응하는 이벤트 종류
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EventKind {
+    ActivityDetected,
+    IdleTimeout,
+    CommitDetected,
+    ErrorDetected,
+    UserClicked,
+    PomodoroCompleted,
+    /// 실패했던 빌드 / 테스트가 다시 통과
+    BuildFixed,
+    LateNightCoding,
+    /// 쉬라는 알림 (오래 연속 코딩 / 하루 한도 / 연속 밤 코딩)
+    Overworked,
+    TimerExpired,
+    /// 모든 이벤트
+    #[serde(rename = "*")]
+    Any,
+}
+
+/// 전환 조건 (모두 선택, 지정한 조건을 모두 만족해야 전환)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Guard {
+    /// 설정된 밤 시간대 여부
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub night: Option<bool>,
+    /// 시각 구간 [시작, 끝) (24h, 시작 > 끝이면 자정을 넘김)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<(u32, u32)>,
+    /// `idleTimeout` 이벤트의 최소 유휴 시간
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_idle: Option<IdleThreshold>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_level: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_streak: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pomodoro: Option<PomodoroPhase>,
+    /// `userClicked` 이벤트의 클릭 방식
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub click: Option<ClickKind>,
+    /// 쉬라는 알림 뒤 아직 충분히 쉬지 않았는지
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_break: Option<bool>,
+    /// 방해 금지 중인지
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quiet: Option<bool>,
+}
+
+/// 유휴 기준: 설정값 이름 또는 초
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum IdleThreshold {
+    Setting(IdleSetting),
+    Seconds(u64),
+}
+
+/// 설정의 유휴 기준
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum IdleSetting {
+    /// `idleThresholdSeconds`
+    Idle,
+    /// `sleepThresholdSeconds`
+    Sleep,
+}
+
+/// 뽀모도로 진행 단계
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum PomodoroPhase {
+    #[default]
+    Off,
+    Work,
+}
+
+/// 기본 내장 행동
+pub fn default_behavior() -> BehaviorSpec {
+    use EventKind::*;
+
+    let state = |name: &str, mood: CatMood, timeout_secs: Option<u64>| StateSpec {
+        name: name.to_string(),
+        mood,
+        timeout_secs,
+    };
+    let rule = |from: &[&str], on: EventKind, to: Option<&str>, guard: Guard| TransitionSpec {
+        from: from.iter().map(|s| s.to_string()).collect(),
+        on,
+        to: to.map(str::to_string),
+        guard,
+    };
+    let any = Guard::default;
+    let night = |night: bool| Guard {
+        night: Some(night),
+        ..Guard::default()
+    };
+    let idle_at_least = |setting: IdleSetting| Guard {
+        min_idle: Some(IdleThreshold::Setting(setting)),
+        ..Guard::default()
+    };
+    let quiet = || Guard {
+        quiet: Some(true),
+        ..Guard::default()
+    };
+    let temporary = &["celebrating", "frustrated", "interaction"];
+
+    BehaviorSpec {
+        initial: "idle".to_string(),
+        states: vec![
+            state("idle", CatMood::Happy, None),
+            state("coding", CatMood::Focused, None),
+            state("celebrating", CatMood::Excited, Some(5)),
+            state("frustrated", CatMood::Sad, Some(5)),
+            state("sleeping", CatMood::Sleeping, None),
+            state("tired", CatMood::Sad, None),
+            state("interaction", CatMood::Happy, Some(3)),
+        ],
+        transitions: vec![
+            // ── 1. 임시 상태는 복귀 타이머만 받는다 ──
+            rule(temporary, TimerExpired, Some("idle"), any()),
+            rule(temporary, Any, None, any()),
+            // ── 2. 즉시 반응 (방해 금지 중에는 축하하지 않음) ──
+            rule(&["*"], CommitDetected, None, quiet()),
+            rule(&["*"], PomodoroCompleted, None, quiet()),
+            rule(&["*"], BuildFixed, None, quiet()),
+            rule(&["*"], CommitDetected, Some("celebrating"), any()),
+            rule(&["*"], PomodoroCompleted, Some("celebrating"), any()),
+            rule(&["*"], BuildFixed, Some("celebrating"), any()),
+            rule(&["sleeping"], ErrorDetected, None, any()),
+            rule(&["*"], ErrorDetected, Some("frustrated"), any()),
+            rule(
+                &["sleeping"],
+                UserClicked,
+                None, // 자는 고양이는 쓰다듬어도 계속 잔다
+                Guard {
+                    click: Some(ClickKind::LongPress),
+                    ..Guard::default()
+                },
+            ),
+            rule(&["sleeping"], UserClicked, Some("idle"), any()),
+            rule(&["*"], UserClicked, Some("interaction"), any()),
+            // ── 3. 유휴 (밤에는 유휴 기준만 넘어도 잠든다) ──
+            rule(
+                &["idle"],
+                IdleTimeout,
+                Some("sleeping"),
+                Guard {
+                    night: Some(true),
+                    ..idle_at_least(IdleSetting::Idle)
+                },
+            ),
+            rule(&["idle"], IdleTimeout, Some("sleeping"), idle_at_least(IdleSetting::Sleep)),
+            rule(&["coding", "tired"], IdleTimeout, Some("sleeping"), idle_at_least(IdleSetting::Sleep)),
+            rule(&["coding", "tired"], IdleTimeout, Some("idle"), idle_at_least(IdleSetting::Idle)),
+            // ── 4. 밤 코딩 / 쉬라는 알림 → tired ──
+            rule(&["coding"], LateNightCoding, Some("tired"), night(true)),
+            rule(&["idle", "coding"], Overworked, Some("tired"), any()),
+            // ── 5. 활동 재개 ──
+            rule(&["sleeping"], ActivityDetected, Some("idle"), any()),
+            rule(&["idle"], ActivityDetected, Some("coding"), any()),
+            rule(
+                &["tired"],
+                ActivityDetected,
+                None, // 쉬라는 알림 뒤에는 쉬어야 회복
+                Guard {
+                    needs_break: Some(true),
+                    ..Guard::default()
+                },
+            ),
+            rule(&["tired"], ActivityDetected, Some("coding"), night(false)), // 아침이 되면 회복
+        ],
+    }
+}
@@ -1,57 +1,176 @@
 use serde::{Deserialize, Serialize};
 
 /// 고양이의 현재 상태 (상태 머신)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub enum CatState {
-    Idle,
-    Coding,
-    Celebrating,
-    Frustrated,
-    Sleeping,
-    Tired,
-    Interaction,
+///
+/// 상태 목록은 행동 정의 파일(cat-behavior.json)에서 정하므로 이름으로 다룬다.
+/// 기본 상태: idle, coding, celebrating, frustrated, sleeping, tired, interaction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct CatState(pub String);
+
+impl CatState {
+    pub fn new(name: &str) -> Self {
+        Self(name.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 impl Default for CatState {
     fn default() -> Self {
-        CatState::Idle
+        Self::new("idle")
     }
 }
 
-/// 고양이의 현재 표정/이모지
+/// `cat:state-changed` 이벤트 내용
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct CatStateChanged {
+    pub state: CatState,
+    pub previous: CatState,
+}
+
+/// 고양이의 현재 표정/이모지
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub enum CatMood {
     Happy,      // 😺
     Sad,        // 😿
     Sleeping,   // 😴
     Focused,    // 🔥
     Excited,    // 💥
+    Proud,      // 😼 꾸준한 커밋 / 스트릭 / 뽀모도로
+    Worried,    // 🙀 빌드 실패 / 밤 코딩이 잦음
+    Bored,      // 😑 한동안 커밋도 코딩도 없음
 }
 
-impl From<&CatState> for CatMood {
-    fn from(state: &CatState) -> Self {
-        match state {
-            CatState::Idle => CatMood::Happy,
-            CatState::Coding => CatMood::Focused,
-            CatState::Celebrating => CatMood::Excited,
-            CatState::Frustrated => CatMood::Sad,
-            CatState::Sleeping => CatMood::Sleeping,
-            CatState::Tired => CatMood::Sad,
-            CatState::Interaction => CatMood::Happy,
-        }
-    }
+/// 고양이를 만지는 방식
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ClickKind {
+    #[default]
+    Single,
+    Double,
+    /// 길게 누르기 = 쓰다듬기
+    LongPress,
+    /// 끌어서 옮기기
+    Drag,
+}
+
+/// `cat:reaction` 이벤트 내용 (오버레이가 그대로 보여줌)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CatReaction {
+    pub kind: ClickKind,
+    /// 반응 애니메이션 이름 (purr, headbutt, hiss 등)
+    pub reaction: String,
+    pub emoji: String,
+    pub message: String,
+    /// 이번 반응으로 얻은 애정도 (쿨다운 / 귀찮을 때는 0)
+    pub affection_gained: u32,
+    /// 누적 애정도
+    pub affection: u32,
+    /// 너무 자주 만져서 귀찮아함
+    pub annoyed: bool,
 }
 
 /// 프론트엔드로 전달하는 고양이 전체 상태
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CatInfo {
+    /// 선택된 고양이 id / 이름
+    pub id: String,
+    pub name: String,
     pub state: CatState,
     pub mood: CatMood,
     pub level: u32,
     pub exp: u32,
     pub exp_to_next: u32,
     pub streak_days: u32,
+    pub evolution: super::evolution::Evolution,
+    pub affection: u32,
+    /// 착용 중인 꾸미기 아이템 (오버레이가 그림)
+    pub look: super::cosmetic::EquippedLook,
+    pub needs: CatNeeds,
+    /// 표정을 정한 근거 (UI에서 "왜 이런 기분인지" 설명용)
+    pub mood_factors: Vec<MoodFactor>,
+}
+
+/// 고양이 목록 항목 (`list_cats`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatSummary {
+    pub id: String,
+    pub name: String,
+    pub level: u32,
+    pub total_exp: u32,
+    pub streak_days: u32,
+    pub evolution: super::evolution::Evolution,
+    /// EXP를 받을 저장소 경로 / 시간대
+    pub repos: Vec<String>,
+    pub hours: Option<(u32, u32)>,
+    pub active: bool,
+    pub retired_at: Option<i64>,
+}
+
+/// 표정에 반영되는 신호
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MoodSignal {
+    /// 최근 7일 활동한 날의 하루 평균 커밋 수
+    CommitCadence,
+    /// 마지막 커밋 이후 시간 (시간)
+    HoursSinceCommit,
+    /// 연속 활동일
+    Streak,
+    /// 최근 7일 코딩 중 밤 시간 비율 (0~1)
+    LateNight,
+    /// 최근 7일 빌드 / 테스트 실패 비율 (0~1)
+    BuildFailures,
+    /// 최근 7일 뽀모도로 완료 비율 (0~1)
+    PomodoroCompletion,
+    /// 욕구 게이지 (0~100)
+    Hunger,
+    Energy,
+    Happiness,
+}
+
+/// 표정 근거 하나
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MoodFactor {
+    pub signal: MoodSignal,
+    /// 신호 값 (단위는 `MoodSignal` 참고)
+    pub value: f32,
+    /// 이 신호가 끌어당기는 표정 (없으면 영향 없음)
+    pub pushes: Option<CatMood>,
+}
+
+/// 고양이 욕구 게이지 (0~100, 높을수록 만족)
+///
+/// 실제 시간이 흐르면 줄고 코딩 / 커밋 / 뽀모도로 / 휴식으로 채워진다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CatNeeds {
+    /// 배부름 — 커밋과 뽀모도로가 밥
+    pub hunger: f32,
+    /// 기력 — 코딩하면 줄고 쉬면 회복
+    pub energy: f32,
+    /// 행복 — 시간이 지나면 줄고 코딩 / 커밋 / 뽀모도로로 오름
+    pub happiness: f32,
+    /// 마지막으로 시간 감소를 반영한 시각 (unix 초)
+    pub updated_at: Option<i64>,
+}
+
+impl Default for CatNeeds {
+    fn default() -> Self {
+        Self {
+            hunger: 80.0,
+            energy: 80.0,
+            happiness: 80.0,
+            updated_at: None,
+        }
+    }
 }
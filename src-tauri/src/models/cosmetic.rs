@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+/// 꾸미기 아이템 자리 (자리마다 하나씩 착용)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CosmeticSlot {
+    Hat,
+    Collar,
+    /// 털 색 (항상 하나는 착용)
+    Fur,
+    Accessory,
+}
+
+/// 해금 조건
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum UnlockCondition {
+    Level { level: u32 },
+    Achievement { id: String },
+}
+
+/// 아이템 정의
+#[derive(Debug, Clone, Copy)]
+pub struct CosmeticDef {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub slot: CosmeticSlot,
+    /// 오버레이에 그릴 이모지 (털 색은 CSS 클래스 이름)
+    pub icon: &'static str,
+    pub unlock: Unlock,
+}
+
+/// 정의용 해금 조건 (`UnlockCondition`으로 바꿔 전달)
+#[derive(Debug, Clone, Copy)]
+pub enum Unlock {
+    Level(u32),
+    Achievement(&'static str),
+}
+
+const fn item(id: &'static str, name: &'static str, slot: CosmeticSlot, icon: &'static str, unlock: Unlock) -> CosmeticDef {
+    CosmeticDef { id, name, slot, icon, unlock }
+}
+
+/// 기본 털 색
+pub const DEFAULT_FUR: &str = "fur-orange";
+
+/// 아이템 목록 (id는 저장 키이므로 바꾸지 않는다)
+pub const COSMETICS: &[CosmeticDef] = &[
+    // ── 털 색 ──
+    item("fur-orange", "Orange Tabby", CosmeticSlot::Fur, "orange", Unlock::Level(1)),
+    item("fur-gray", "Gray", CosmeticSlot::Fur, "gray", Unlock::Level(3)),
+    item("fur-black", "Black", CosmeticSlot::Fur, "black", Unlock::Level(5)),
+    item("fur-white", "White", CosmeticSlot::Fur, "white", Unlock::Level(10)),
+    item("fur-calico", "Calico", CosmeticSlot::Fur, "calico", Unlock::Achievement("streak-30")),
+    // ── 모자 ──
+    item("hat-cap", "Cap", CosmeticSlot::Hat, "🧢", Unlock::Level(2)),
+    item("hat-top", "Top Hat", CosmeticSlot::Hat, "🎩", Unlock::Level(15)),
+    item("hat-graduation", "Graduation Cap", CosmeticSlot::Hat, "🎓", Unlock::Achievement("commits-100")),
+    item("hat-crown", "Crown", CosmeticSlot::Hat, "👑", Unlock::Achievement("coding-1000h")),
+    // ── 목걸이 ──
+    item("collar-ribbon", "Ribbon", CosmeticSlot::Collar, "🎀", Unlock::Achievement("first-commit")),
+    item("collar-bell", "Bell Collar", CosmeticSlot::Collar, "🔔", Unlock::Level(4)),
+    item("collar-scarf", "Scarf", CosmeticSlot::Collar, "🧣", Unlock::Achievement("weekend-off")),
+    // ── 액세서리 ──
+    item("acc-flower", "Flower", CosmeticSlot::Accessory, "🌸", Unlock::Achievement("streak-7")),
+    item("acc-glasses", "Glasses", CosmeticSlot::Accessory, "👓", Unlock::Achievement("coding-100h")),
+    item("acc-tomato", "Tomato Plush", CosmeticSlot::Accessory, "🍅", Unlock::Achievement("pomodoro-100")),
+    item("acc-headphones", "Headphones", CosmeticSlot::Accessory, "🎧", Unlock::Level(20)),
+];
+
+/// 착용 중인 아이템 (자리별 id)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EquippedLook {
+    pub hat: Option<String>,
+    pub collar: Option<String>,
+    pub fur: String,
+    pub accessory: Option<String>,
+}
+
+impl Default for EquippedLook {
+    fn default() -> Self {
+        Self {
+            hat: None,
+            collar: None,
+            fur: DEFAULT_FUR.to_string(),
+            accessory: None,
+        }
+    }
+}
+
+/// 인벤토리 아이템 하나 (프론트엔드 전달용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CosmeticItem {
+    pub id: String,
+    pub name: String,
+    pub slot: CosmeticSlot,
+    pub icon: String,
+    pub unlock: UnlockCondition,
+    /// 해금 시각 (unix 초, 잠겨 있으면 None)
+    pub unlocked_at: Option<i64>,
+    pub equipped: bool,
+}
+
+/// `get_inventory` 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Inventory {
+    pub items: Vec<CosmeticItem>,
+    pub equipped: EquippedLook,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// 방해 금지가 켜진 이유
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum DndReason {
+    /// 직접 켬
+    Manual,
+    /// 설정한 조용한 시간
+    QuietHours,
+    /// 뽀모도로 집중 중
+    Pomodoro,
+    /// 화면 공유 중 (공유 표시 창이 열려 있음)
+    ScreenSharing,
+}
+
+/// 방해 금지 상태 (`get_dnd_status`, `dnd:changed`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DndStatus {
+    pub active: bool,
+    pub reasons: Vec<DndReason>,
+    /// 직접 켠 방해 금지가 끝나는 시각 (unix 초, 끌 때까지면 None)
+    pub manual_until: Option<i64>,
+    /// 감지된 화면 공유 / 화상 회의 앱
+    pub sharing_app: Option<String>,
+    /// 끝나면 보낼 알림 / 이벤트 수
+    pub queued: usize,
+}
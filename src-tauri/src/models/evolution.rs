@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+/// 성장 단계
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum LifeStage {
+    #[default]
+    Kitten,
+    Young,
+    Adult,
+    Elder,
+    Legendary,
+}
+
+/// 단계별 최소 레벨
+pub const STAGE_LEVELS: &[(LifeStage, u32)] = &[
+    (LifeStage::Kitten, 1),
+    (LifeStage::Young, 5),
+    (LifeStage::Adult, 15),
+    (LifeStage::Elder, 30),
+    (LifeStage::Legendary, 50),
+];
+
+impl LifeStage {
+    pub fn for_level(level: u32) -> Self {
+        STAGE_LEVELS
+            .iter()
+            .rev()
+            .find(|(_, min_level)| level >= *min_level)
+            .map_or(LifeStage::Kitten, |(stage, _)| *stage)
+    }
+}
+
+/// 진화 갈래 (직전 기간에 가장 두드러진 활동)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EvolutionBranch {
+    /// 두드러진 활동 없음
+    Balanced,
+    /// 커밋을 자주 함
+    Committer,
+    /// 뽀모도로로 집중
+    Focused,
+    /// 밤에 주로 코딩
+    NightOwl,
+}
+
+/// 현재 진화 상태
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Evolution {
+    pub stage: LifeStage,
+    /// 새끼 고양이 때는 없음
+    pub branch: Option<EvolutionBranch>,
+    /// 마지막 진화 시각 (unix 초) — 다음 갈래는 이후 활동으로 정한다
+    pub evolved_at: Option<i64>,
+}
+
+/// `cat:evolved` 이벤트 내용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvolutionChanged {
+    pub previous: LifeStage,
+    pub evolution: Evolution,
+    /// 갈래 점수 (1.0 이상이면 두드러짐)
+    pub scores: BranchScores,
+}
+
+/// 갈래별 점수 (기준치 대비 배수)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchScores {
+    pub committer: f32,
+    pub focused: f32,
+    pub night_owl: f32,
+}
@@ -10,6 +10,16 @@ pub struct LevelInfo {
     pub total_exp: u32,
 }
 
+/// 오늘 얻은 EXP 출처별 내역
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpBreakdown {
+    pub coding: u32,
+    pub commits: u32,
+    pub pomodoro: u32,
+    pub total: u32,
+}
+
 /// EXP 배율 상수
 pub const EXP_PER_CODING_MINUTE: u32 = 1;
 pub const EXP_PER_COMMIT: u32 = 20;
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+/// IDE / 에디터 분류
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum IdeCategory {
+    /// 통합 개발 환경 (IntelliJ, VS Code ...)
+    Ide,
+    /// GUI 텍스트 에디터 (Sublime, Kate, Emacs ...)
+    Editor,
+    /// 터미널 안에서 도는 에디터 (vim, neovim, helix ...)
+    /// TTY 포그라운드에서 실행 중일 때만 인정
+    TerminalEditor,
+    /// 터미널 에뮬레이터 (IDE는 아님, 터미널 에디터 포커스 판정용)
+    Terminal,
+}
+
+/// IDE 감지 규칙 (ide-rules.json 한 항목)
+///
+/// 규칙은 위에서부터 순서대로 비교하며 처음 일치한 규칙이 적용된다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IdeRule {
+    /// 실행 파일 이름
+    /// - Linux: 실행 파일 basename (예: "code", "idea")
+    /// - macOS: 앱 번들 이름 (예: "Visual Studio Code.app")
+    /// - Windows: 이미지 이름 (예: "Code.exe")
+    pub executable: String,
+    /// 커맨드라인 인자 정규식 (선택, Windows에서는 무시)
+    #[serde(default)]
+    pub args_pattern: Option<String>,
+    /// X11 WM_CLASS (선택, 포커스 감지용)
+    #[serde(default)]
+    pub window_class: Option<String>,
+    /// 표시 이름 (예: "VS Code")
+    pub name: String,
+    pub category: IdeCategory,
+    /// 적용 OS ("linux" | "macos" | "windows"), 비어 있으면 전체
+    #[serde(default)]
+    pub platforms: Vec<String>,
+}
+
+impl IdeRule {
+    /// 현재 OS에 적용되는 규칙인지
+    pub fn applies_to_current_os(&self) -> bool {
+        self.platforms.is_empty() || self.platforms.iter().any(|p| p == std::env::consts::OS)
+    }
+}
+
+/// 내장 규칙 한 줄: (OS, 실행 파일, 인자 정규식, WM_CLASS, 표시 이름, 분류)
+type RuleRow<'a> = (&'a str, &'a str, Option<&'a str>, Option<&'a str>, &'a str, IdeCategory);
+
+/// 기본 내장 규칙
+pub fn default_ide_rules() -> Vec<IdeRule> {
+    use IdeCategory::*;
+
+    let table: &[RuleRow] = &[
+        // ── macOS: 앱 번들 경로 ──
+        // VS Code 계열
+        ("macos", "Visual Studio Code.app", None, None, "VS Code", Ide),
+        ("macos", "Visual Studio Code - Insiders.app", None, None, "VS Code Insiders", Ide),
+        ("macos", "Cursor.app", None, None, "Cursor", Ide),
+        ("macos", "Windsurf.app", None, None, "Windsurf", Ide),
+        // JetBrains 계열
+        ("macos", "IntelliJ IDEA.app", None, None, "IntelliJ IDEA", Ide),
+        ("macos", "IntelliJ IDEA CE.app", None, None, "IntelliJ IDEA", Ide),
+        ("macos", "WebStorm.app", None, None, "WebStorm", Ide),
+        ("macos", "PyCharm.app", None, None, "PyCharm", Ide),
+        ("macos", "PyCharm CE.app", None, None, "PyCharm", Ide),
+        ("macos", "GoLand.app", None, None, "GoLand", Ide),
+        ("macos", "CLion.app", None, None, "CLion", Ide),
+        ("macos", "RustRover.app", None, None, "RustRover", Ide),
+        ("macos", "DataGrip.app", None, None, "DataGrip", Ide),
+        ("macos", "Rider.app", None, None, "Rider", Ide),
+        // 기타
+        ("macos", "Xcode.app", None, None, "Xcode", Ide),
+        ("macos", "Zed.app", None, None, "Zed", Editor),
+        ("macos", "Sublime Text.app", None, None, "Sublime Text", Editor),
+        ("macos", "Android Studio.app", None, None, "Android Studio", Ide),
+        // ── Windows: 이미지 이름 ──
+        ("windows", "Code.exe", None, None, "VS Code", Ide),
+        ("windows", "Cursor.exe", None, None, "Cursor", Ide),
+        ("windows", "devenv.exe", None, None, "Visual Studio", Ide),
+        ("windows", "idea64.exe", None, None, "IntelliJ IDEA", Ide),
+        ("windows", "webstorm64.exe", None, None, "WebStorm", Ide),
+        ("windows", "pycharm64.exe", None, None, "PyCharm", Ide),
+        ("windows", "goland64.exe", None, None, "GoLand", Ide),
+        ("windows", "clion64.exe", None, None, "CLion", Ide),
+        ("windows", "rustrover64.exe", None, None, "RustRover", Ide),
+        ("windows", "datagrip64.exe", None, None, "DataGrip", Ide),
+        ("windows", "rider64.exe", None, None, "Rider", Ide),
+        // ── Linux: 실행 파일 basename ──
+        // VS Code 계열
+        ("linux", "code", None, Some("Code"), "VS Code", Ide),
+        ("linux", "code-insiders", None, Some("Code - Insiders"), "VS Code Insiders", Ide),
+        ("linux", "cursor", None, Some("Cursor"), "Cursor", Ide),
+        ("linux", "windsurf", None, Some("Windsurf"), "Windsurf", Ide),
+        // JetBrains 계열
+        ("linux", "idea", None, Some("jetbrains-idea"), "IntelliJ IDEA", Ide),
+        ("linux", "idea", None, Some("jetbrains-idea-ce"), "IntelliJ IDEA", Ide),
+        ("linux", "webstorm", None, Some("jetbrains-webstorm"), "WebStorm", Ide),
+        ("linux", "pycharm", None, Some("jetbrains-pycharm"), "PyCharm", Ide),
+        ("linux", "pycharm", None, Some("jetbrains-pycharm-ce"), "PyCharm", Ide),
+        ("linux", "goland", None, Some("jetbrains-goland"), "GoLand", Ide),
+        ("linux", "clion", None, Some("jetbrains-clion"), "CLion", Ide),
+        ("linux", "rustrover", None, Some("jetbrains-rustrover"), "RustRover", Ide),
+        ("linux", "datagrip", None, Some("jetbrains-datagrip"), "DataGrip", Ide),
+        ("linux", "rider", None, Some("jetbrains-rider"), "Rider", Ide),
+        ("linux", "studio", None, Some("jetbrains-studio"), "Android Studio", Ide),
+        ("linux", "fleet", None, Some("Fleet"), "Fleet", Ide),
+        // 에디터
+        ("linux", "zed", None, Some("dev.zed.Zed"), "Zed", Editor),
+        ("linux", "zeditor", None, Some("dev.zed.Zed"), "Zed", Editor),
+        ("linux", "sublime_text", None, Some("sublime_text"), "Sublime Text", Editor),
+        ("linux", "kate", None, Some("kate"), "Kate", Editor),
+        // 터미널 에디터 (emacs -nw는 GUI emacs보다 먼저 비교)
+        ("linux", "emacs", Some(r"(^| )(-nw|--no-window-system)( |$)"), None, "Emacs", TerminalEditor),
+        ("linux", "emacsclient", Some(r"(^| )(-t|-nw|--tty)( |$)"), None, "Emacs", TerminalEditor),
+        ("linux", "emacs", None, Some("Emacs"), "Emacs", Editor),
+        ("linux", "nvim", None, None, "Neovim", TerminalEditor),
+        ("linux", "vim", None, None, "Vim", TerminalEditor),
+        ("linux", "vi", None, None, "Vim", TerminalEditor),
+        ("linux", "hx", None, None, "Helix", TerminalEditor),
+        ("linux", "helix", None, None, "Helix", TerminalEditor),
+        ("linux", "nano", None, None, "nano", TerminalEditor),
+        // 터미널 에뮬레이터
+        ("linux", "gnome-terminal-server", None, Some("Gnome-terminal"), "GNOME Terminal", Terminal),
+        ("linux", "kgx", None, Some("org.gnome.Console"), "GNOME Console", Terminal),
+        ("linux", "konsole", None, Some("konsole"), "Konsole", Terminal),
+        ("linux", "kitty", None, Some("kitty"), "kitty", Terminal),
+        ("linux", "alacritty", None, Some("Alacritty"), "Alacritty", Terminal),
+        ("linux", "wezterm-gui", None, Some("org.wezfurlong.wezterm"), "WezTerm", Terminal),
+        ("linux", "ghostty", None, Some("com.mitchellh.ghostty"), "Ghostty", Terminal),
+        ("linux", "foot", None, Some("foot"), "foot", Terminal),
+        ("linux", "tilix", None, Some("Tilix"), "Tilix", Terminal),
+        ("linux", "terminator", None, Some("Terminator"), "Terminator", Terminal),
+        ("linux", "xfce4-terminal", None, Some("Xfce4-terminal"), "Xfce Terminal", Terminal),
+        ("linux", "xterm", None, Some("XTerm"), "XTerm", Terminal),
+    ];
+
+    table
+        .iter()
+        .map(|(os, executable, args_pattern, window_class, name, category)| IdeRule {
+            executable: executable.to_string(),
+            args_pattern: args_pattern.map(str::to_string),
+            window_class: window_class.map(str::to_string),
+            name: name.to_string(),
+            category: *category,
+            platforms: vec![os.to_string()],
+        })
+        .collect()
+}
@@ -2,3 +2,10 @@ pub mod cat;
 pub mod activity;
 pub mod settings;
 pub mod growth;
+pub mod ide;
+pub mod behavior;
+pub mod achievement;
+pub mod cosmetic;
+pub mod evolution;
+pub mod wellbeing;
+pub mod dnd;
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// 앱 설정 (권한 토글 포함)
+///
+/// 새 필드가 추가되어도 기존 데이터 파일을 읽을 수 있도록 누락된 값은 기본값으로 채운다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct AppSettings {
     /// 풀스크린 자동 숨김
     pub auto_hide_fullscreen: bool,
@@ -12,6 +15,8 @@ pub struct AppSettings {
     pub ide_detection: bool,
     /// Git 연동 허용
     pub git_integration: bool,
+    /// 빌드 / 테스트 결과 감지 허용 (notify 소켓 + JUnit 리포트)
+    pub build_signals: bool,
     /// Docker 감지 허용 (v2)
     pub docker_integration: bool,
     /// AI 기능 (v3)
@@ -20,10 +25,40 @@ pub struct AppSettings {
     pub pomodoro_minutes: u32,
     /// 유휴 판정 시간 (초)
     pub idle_threshold_seconds: u64,
+    /// 수면 판정 시간 (초)
+    pub sleep_threshold_seconds: u64,
     /// 밤 시간 시작 (시, 24h)
     pub night_hour_start: u32,
     /// 밤 시간 끝 (시, 24h)
     pub night_hour_end: u32,
+    /// 밤 코딩 알림 허용
+    pub late_night_reminders: bool,
+    /// 밤 시간에 이만큼 코딩하면 첫 알림 (분)
+    pub late_night_reminder_minutes: u32,
+    /// 첫 알림 이후 다시 알리는 간격 (분) → 알릴 때마다 피곤함 단계 상승
+    pub late_night_repeat_minutes: u32,
+    /// 휴식 알림 허용 (오래 연속 코딩 / 하루 한도 / 연속 밤 코딩)
+    pub break_reminders: bool,
+    /// 쉬지 않고 이만큼 코딩하면 휴식 알림, 이후 같은 간격마다 다시 (분)
+    pub break_reminder_minutes: u32,
+    /// 이만큼 쉬면 연속 코딩이 끊긴 것으로 봄 (분)
+    pub break_minutes: u32,
+    /// 하루 건강 코딩 한도 (분, 0이면 끔)
+    pub daily_limit_minutes: u32,
+    /// 한도를 넘긴 코딩 시간은 EXP를 줄여서 적립
+    pub reduce_exp_over_limit: bool,
+    /// 밤 코딩이 이만큼 이어진 밤이 되면 알림 (0이면 끔)
+    pub late_night_streak_nights: u32,
+    /// 조용한 시간 [시작, 끝) (24h, 시작 > 끝이면 자정을 넘김, 끝 24는 자정, None이면 끔)
+    pub quiet_hours: Option<(u32, u32)>,
+    /// 뽀모도로 집중 중에는 방해 금지
+    pub dnd_during_pomodoro: bool,
+    /// 화면 공유 / 화상 회의 중에는 방해 금지
+    pub dnd_while_screen_sharing: bool,
+    /// 화면 공유 중일 때 표시할 회의 앱 프로세스 이름 (대소문자 무시, .exe 생략)
+    pub screen_sharing_apps: Vec<String>,
+    /// 화면 공유로 보는 창 제목 일부 (공유 표시줄 / 브라우저 공유 알림, 하나는 열려 있어야 함)
+    pub screen_sharing_titles: Vec<String>,
     /// 등록된 Git 저장소 경로들
     pub git_repos: Vec<String>,
 }
@@ -35,38 +70,160 @@ impl Default for AppSettings {
             activity_tracking: true,  // MVP에서는 기본 ON
             ide_detection: true,
             git_integration: true,
+            build_signals: true,
             docker_integration: false, // v2
             ai_enabled: false,         // v3
             pomodoro_minutes: 25,
             idle_threshold_seconds: 300, // 5분
+            sleep_threshold_seconds: 600, // 10분
             night_hour_start: 23,
             night_hour_end: 6,
+            late_night_reminders: true,
+            late_night_reminder_minutes: 30,
+            late_night_repeat_minutes: 30,
+            break_reminders: true,
+            break_reminder_minutes: 50,
+            break_minutes: 5,
+            daily_limit_minutes: 8 * 60,
+            reduce_exp_over_limit: false,
+            late_night_streak_nights: 3,
+            quiet_hours: None,
+            dnd_during_pomodoro: true,
+            dnd_while_screen_sharing: true,
+            screen_sharing_apps: ["zoom", "zoom.us", "CptHost", "teams", "ms-teams", "webex"]
+                .map(str::to_string)
+                .to_vec(),
+            screen_sharing_titles: [
+                "is sharing your screen",
+                "You are screen sharing",
+                "Sharing control bar",
+                "Screen sharing",
+            ]
+            .map(str::to_string)
+            .to_vec(),
             git_repos: vec![],
         }
     }
 }
 
+impl AppSettings {
+    /// 주어진 시각(0~23시)이 밤 시간대인지 판정
+    ///
+    /// 시작 > 끝이면 자정을 넘기는 구간(예: 23시 ~ 6시)으로 본다.
+    pub fn is_night_hour(&self, hour: u32) -> bool {
+        in_hours((self.night_hour_start, self.night_hour_end), hour)
+    }
+
+    /// 설정한 조용한 시간인지
+    pub fn is_quiet_hour(&self, hour: u32) -> bool {
+        self.quiet_hours.is_some_and(|hours| in_hours(hours, hour))
+    }
+}
+
+/// 시각(0~23시)이 [시작, 끝) 구간 안인지 (시작 > 끝이면 자정을 넘김, 같으면 빈 구간)
+pub fn in_hours((start, end): (u32, u32), hour: u32) -> bool {
+    if start == end {
+        false
+    } else if start < end {
+        hour >= start && hour < end
+    } else {
+        hour >= start || hour < end
+    }
+}
+
+/// 시간대 검증: 시작 0~23, 끝 0~24 (끝은 제외, 24는 자정), 빈 구간은 거부
+pub fn valid_hours((start, end): (u32, u32)) -> Result<(), String> {
+    if start > 23 || end > 24 || start == end {
+        return Err(format!("Invalid hours: {}-{}", start, end));
+    }
+    Ok(())
+}
+
 /// 로컬 저장 전체 데이터
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct AppData {
     pub version: u32,
     pub settings: AppSettings,
+    /// 선택된 고양이의 데이터 (나머지 고양이는 `cats`의 각 프로필에 보관)
     pub cat: CatPersistence,
+    /// 선택된 고양이 id
+    pub active_cat: String,
+    /// 고양이 목록 (은퇴한 고양이 포함)
+    pub cats: Vec<CatProfile>,
     pub today: super::activity::DailySummary,
     pub history: Vec<super::activity::DailySummary>,
+    /// 진행 중인 코딩 세션 (재시작 시 이어가기)
+    pub session: super::activity::SessionSnapshot,
+}
+
+/// 기본 고양이 id (여러 마리 이전 저장 데이터의 고양이)
+pub const DEFAULT_CAT_ID: &str = "default";
+
+/// 고양이 프로필 (이름 + EXP를 받을 규칙)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CatProfile {
+    pub id: String,
+    pub name: String,
+    /// 이 경로(경로 컴포넌트 단위, 심볼릭 링크 정규화) 아래 저장소의 활동은 이 고양이에게 (가장 깊은 규칙 우선)
+    pub repos: Vec<String>,
+    /// 이 시간대 [시작, 끝) 시각의 활동은 이 고양이에게 (끝 < 시작이면 자정을 넘김, 끝 24는 자정)
+    pub hours: Option<(u32, u32)>,
+    /// 생성 시각 (unix 초)
+    pub created_at: Option<i64>,
+    /// 은퇴 시각 (unix 초) — 은퇴하면 EXP를 받지 않고 선택할 수 없다
+    pub retired_at: Option<i64>,
+    /// 선택되지 않은 동안의 데이터 (선택된 고양이는 `AppData.cat`에 있고 여기는 비어 있음)
+    pub data: CatPersistence,
+}
+
+impl Default for CatProfile {
+    fn default() -> Self {
+        Self {
+            id: DEFAULT_CAT_ID.to_string(),
+            name: "Commit Cat".to_string(),
+            repos: vec![],
+            hours: None,
+            created_at: None,
+            retired_at: None,
+            data: CatPersistence::default(),
+        }
+    }
 }
 
 /// 고양이 영구 데이터 (레벨/경험치)
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct CatPersistence {
     pub level: u32,
+    /// 현재 레벨에서 쌓은 EXP
     pub exp: u32,
+    /// 누적 EXP
+    pub total_exp: u32,
     pub total_coding_minutes: u32,
+    /// 언어별 누적 코딩 시간 (분) — 언어 테마 액세서리 해금용
+    pub language_minutes: BTreeMap<String, u32>,
     pub total_commits: u32,
+    pub total_pomodoros: u32,
+    /// 마지막 커밋 감지 시각 (unix 초)
+    pub last_commit_at: Option<i64>,
     pub streak_days: u32,
     pub last_active_date: Option<String>,
+    /// 마지막 상태 머신 상태 (재시작 시 복원)
+    pub state: super::cat::CatState,
+    /// 욕구 게이지
+    pub needs: super::cat::CatNeeds,
+    /// 쓰다듬고 놀아 준 만큼 쌓이는 애정도
+    pub affection: u32,
+    /// 업적 id → 해금 시각 (unix 초)
+    pub achievements: BTreeMap<String, i64>,
+    /// 꾸미기 아이템 id → 해금 시각 (unix 초)
+    pub items: BTreeMap<String, i64>,
+    /// 착용 중인 아이템
+    pub equipped: super::cosmetic::EquippedLook,
+    /// 성장 단계 / 진화 갈래
+    pub evolution: super::evolution::Evolution,
 }
 
 impl Default for CatPersistence {
@@ -74,10 +231,21 @@ impl Default for CatPersistence {
         Self {
             level: 1,
             exp: 0,
+            total_exp: 0,
             total_coding_minutes: 0,
+            language_minutes: BTreeMap::new(),
             total_commits: 0,
+            total_pomodoros: 0,
+            last_commit_at: None,
             streak_days: 0,
             last_active_date: None,
+            state: super::cat::CatState::default(),
+            needs: super::cat::CatNeeds::default(),
+            affection: 0,
+            achievements: BTreeMap::new(),
+            items: BTreeMap::new(),
+            equipped: super::cosmetic::EquippedLook::default(),
+            evolution: super::evolution::Evolution::default(),
         }
     }
 }
@@ -88,8 +256,11 @@ impl Default for AppData {
             version: 1,
             settings: AppSettings::default(),
             cat: CatPersistence::default(),
+            active_cat: DEFAULT_CAT_ID.to_string(),
+            cats: vec![CatProfile::default()],
             today: super::activity::DailySummary::default(),
             history: vec![],
+            session: super::activity::SessionSnapshot::default(),
         }
     }
 }
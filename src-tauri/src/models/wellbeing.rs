@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// 쉬라고 알릴 이유 (`wellbeing:reminder` 이벤트 / 시스템 알림)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum WellbeingReminder {
+    /// 쉬지 않고 오래 코딩 (이번 연속 코딩 시간, 분)
+    #[serde(rename_all = "camelCase")]
+    LongStretch { minutes: u32 },
+    /// 오늘 코딩 시간이 하루 한도를 넘음 (분)
+    #[serde(rename_all = "camelCase")]
+    DailyLimit { minutes: u32, limit_minutes: u32 },
+    /// 며칠 밤 연속으로 밤 코딩
+    #[serde(rename_all = "camelCase")]
+    LateNightStreak { nights: u32 },
+}
@@ -0,0 +1,223 @@
+use crate::events::event_names;
+use crate::models::achievement::{Achievement, AchievementMetric, AchievementRule, ACHIEVEMENTS};
+use crate::models::settings::AppData;
+use crate::services::dnd;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Weekday};
+use tauri::AppHandle;
+
+/// 새로 달성한 업적을 해금 기록에 남기고 반환
+///
+/// 저장하는 쪽(`storage::update`) 안에서 기록을 바꾼 직후 부른다.
+pub fn unlock_new(data: &mut AppData, now: DateTime<Local>) -> Vec<Achievement> {
+    let today = now.date_naive();
+    let mut unlocked = Vec::new();
+    for rule in ACHIEVEMENTS {
+        if data.cat.achievements.contains_key(rule.id) {
+            continue;
+        }
+        let progress = metric(data, rule.metric, today);
+        if progress >= rule.goal {
+            data.cat.achievements.insert(rule.id.to_string(), now.timestamp());
+            unlocked.push(achievement(rule, progress, Some(now.timestamp())));
+        }
+    }
+    unlocked
+}
+
+/// 전체 업적 + 진행도
+pub fn list(data: &AppData, today: NaiveDate) -> Vec<Achievement> {
+    ACHIEVEMENTS
+        .iter()
+        .map(|rule| {
+            let unlocked_at = data.cat.achievements.get(rule.id).copied();
+            let progress = match unlocked_at {
+                Some(_) => rule.goal,
+                None => metric(data, rule.metric, today),
+            };
+            achievement(rule, progress, unlocked_at)
+        })
+        .collect()
+}
+
+/// 해금마다 `cat:achievement-unlocked` 발생
+pub fn emit_unlocked(app: &AppHandle, unlocked: &[Achievement]) {
+    for achievement in unlocked {
+        dnd::emit(app, event_names::CAT_ACHIEVEMENT_UNLOCKED, achievement);
+    }
+}
+
+fn achievement(rule: &AchievementRule, progress: u32, unlocked_at: Option<i64>) -> Achievement {
+    Achievement {
+        id: rule.id.to_string(),
+        name: rule.name.to_string(),
+        description: rule.description.to_string(),
+        icon: rule.icon.to_string(),
+        progress: progress.min(rule.goal),
+        goal: rule.goal,
+        unlocked_at,
+    }
+}
+
+fn metric(data: &AppData, metric: AchievementMetric, today: NaiveDate) -> u32 {
+    match metric {
+        AchievementMetric::TotalCommits => data.cat.total_commits,
+        AchievementMetric::StreakDays => data.cat.streak_days,
+        AchievementMetric::TotalPomodoros => data.cat.total_pomodoros,
+        AchievementMetric::CodingHours => data.cat.total_coding_minutes / 60,
+        AchievementMetric::ReposCommittedToday => data.today.repo_commits.len() as u32,
+        AchievementMetric::WeekendOff => weekend_off(data, today) as u32,
+    }
+}
+
+/// 지난 주말(토·일) 코딩 없음 + 그 전 평일에 코딩함
+///
+/// 주말이 끝난 뒤(월~금)에만 판정한다. 기록이 없는 날은 코딩하지 않은 날이다.
+fn weekend_off(data: &AppData, today: NaiveDate) -> bool {
+    if matches!(today.weekday(), Weekday::Sat | Weekday::Sun) {
+        return false;
+    }
+    let coded = |date: NaiveDate| {
+        let date = date.format("%Y-%m-%d").to_string();
+        std::iter::once(&data.today)
+            .chain(&data.history)
+            .any(|day| day.date == date && day.coding_minutes > 0)
+    };
+
+    let sunday = today - Duration::days(today.weekday().num_days_from_sunday() as i64);
+    let saturday = sunday - Duration::days(1);
+    let worked_week = (1..=5).any(|days| coded(saturday - Duration::days(days)));
+    worked_week && !coded(saturday) && !coded(sunday)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::activity::DailySummary;
+    use chrono::TimeZone;
+
+    /// 2026-03-09 (월) 정오
+    fn monday() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 3, 9, 12, 0, 0).unwrap()
+    }
+
+    fn set(data: &mut AppData, metric: AchievementMetric, value: u32) {
+        match metric {
+            AchievementMetric::TotalCommits => data.cat.total_commits = value,
+            AchievementMetric::StreakDays => data.cat.streak_days = value,
+            AchievementMetric::TotalPomodoros => data.cat.total_pomodoros = value,
+            AchievementMetric::CodingHours => data.cat.total_coding_minutes = value * 60,
+            AchievementMetric::ReposCommittedToday => {
+                data.today.repo_commits = (0..value).map(|i| (format!("/repo/{}", i), 1)).collect();
+            }
+            AchievementMetric::WeekendOff => {
+                if value > 0 {
+                    data.history = coding_days(&["2026-03-06"]);
+                }
+            }
+        }
+    }
+
+    fn coding_days(dates: &[&str]) -> Vec<DailySummary> {
+        dates
+            .iter()
+            .map(|date| DailySummary {
+                date: date.to_string(),
+                coding_minutes: 30,
+                ..DailySummary::default()
+            })
+            .collect()
+    }
+
+    fn ids(unlocked: &[Achievement]) -> Vec<&str> {
+        unlocked.iter().map(|a| a.id.as_str()).collect()
+    }
+
+    #[test]
+    fn each_achievement_unlocks_exactly_at_its_goal() {
+        for rule in ACHIEVEMENTS {
+            let mut below = AppData::default();
+            set(&mut below, rule.metric, rule.goal - 1);
+            assert!(
+                !ids(&unlock_new(&mut below, monday())).contains(&rule.id),
+                "{} unlocked below its goal",
+                rule.id
+            );
+
+            let mut at_goal = AppData::default();
+            set(&mut at_goal, rule.metric, rule.goal);
+            let unlocked = unlock_new(&mut at_goal, monday());
+            assert!(ids(&unlocked).contains(&rule.id), "{} not unlocked at its goal", rule.id);
+            assert_eq!(at_goal.cat.achievements.get(rule.id), Some(&monday().timestamp()));
+
+            let unlocked = unlocked.iter().find(|a| a.id == rule.id).unwrap();
+            assert_eq!((unlocked.progress, unlocked.goal), (rule.goal, rule.goal));
+        }
+    }
+
+    #[test]
+    fn unlocking_is_idempotent() {
+        let mut data = AppData::default();
+        data.cat.total_commits = 150;
+        assert_eq!(ids(&unlock_new(&mut data, monday())), vec!["first-commit", "commits-100"]);
+
+        let later = monday() + Duration::days(1);
+        data.cat.total_commits = 200;
+        assert!(unlock_new(&mut data, later).is_empty());
+        assert_eq!(data.cat.achievements.get("first-commit"), Some(&monday().timestamp()));
+
+        // 목록에서도 해금 시각은 처음 그대로, 진행도는 목표치
+        let listed = list(&data, later.date_naive());
+        let first = listed.iter().find(|a| a.id == "first-commit").unwrap();
+        assert_eq!((first.progress, first.unlocked_at), (1, Some(monday().timestamp())));
+    }
+
+    #[test]
+    fn locked_rules_report_progress() {
+        let mut data = AppData::default();
+        data.cat.total_pomodoros = 42;
+        let listed = list(&data, monday().date_naive());
+        let farmer = listed.iter().find(|a| a.id == "pomodoro-100").unwrap();
+        assert_eq!((farmer.progress, farmer.unlocked_at), (42, None));
+    }
+
+    #[test]
+    fn weekend_off_boundaries() {
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        // (오늘, 코딩한 날, 결과) — 2026-03-02(월) ~ 06(금) 평일, 07(토) / 08(일) 주말
+        let cases: &[(&str, &[&str], bool)] = &[
+            ("2026-03-09", &["2026-03-06"], true),
+            ("2026-03-09", &["2026-03-02"], true),
+            ("2026-03-09", &["2026-03-06", "2026-03-09"], true),
+            // 주말 중 하루라도 코딩
+            ("2026-03-09", &["2026-03-06", "2026-03-07"], false),
+            ("2026-03-09", &["2026-03-06", "2026-03-08"], false),
+            // 쉬기 전 주에 코딩하지 않음 (지난 일요일은 그 주가 아님)
+            ("2026-03-09", &[], false),
+            ("2026-03-09", &["2026-03-01"], false),
+            // 주말 당일에는 판정하지 않음
+            ("2026-03-07", &["2026-03-06"], false),
+            ("2026-03-08", &["2026-03-06"], false),
+            // 금요일까지는 지난 주말 기준
+            ("2026-03-13", &["2026-03-06", "2026-03-10"], true),
+            ("2026-03-13", &["2026-03-10"], false),
+        ];
+        for (today, coded, expected) in cases {
+            let data = AppData {
+                history: coding_days(coded),
+                ..AppData::default()
+            };
+            assert_eq!(weekend_off(&data, date(today)), *expected, "{} {:?}", today, coded);
+        }
+    }
+
+    #[test]
+    fn weekend_off_counts_today_summary() {
+        // 오늘 요약(아직 history로 넘어가지 않은 날)도 코딩한 날로 본다
+        let data = AppData {
+            today: coding_days(&["2026-03-08"]).remove(0),
+            history: coding_days(&["2026-03-06"]),
+            ..AppData::default()
+        };
+        assert!(!weekend_off(&data, NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()));
+    }
+}
@@ -1,196 +1,1232 @@
+use crate::events::event_names;
+use crate::models::achievement::Achievement;
+use crate::models::activity::{CodingStatus, SessionSnapshot};
+use crate::models::cat::CatNeeds;
+use crate::models::cosmetic::CosmeticItem;
+use crate::models::evolution::EvolutionChanged;
+use crate::models::growth::EXP_PER_CODING_MINUTE;
+use crate::models::settings::AppSettings;
+use crate::models::wellbeing::WellbeingReminder;
+use crate::services::achievements;
+use crate::services::cats;
+use crate::services::cosmetics;
+use crate::services::dnd;
+use crate::services::evolution;
+use crate::services::growth;
+use crate::services::ide_detect::{self, Focus, RunningIde};
+use crate::services::ide_rules::{IdeRuleSet, IdeRulesState};
+use crate::services::idle;
+use crate::services::needs::{self, NeedsActivity, BREAK_FLUSH_MINUTES};
+use crate::services::project;
+use crate::services::session::{MinuteBuckets, SessionTracker};
+use crate::services::cat_state::CatStateHandle;
+use crate::services::settings::SettingsState;
+use crate::services::state_machine::StateEvent;
+use crate::services::storage;
+use crate::services::wellbeing::{self, Stretch, StretchTracker};
+use chrono::{DateTime, Local, Timelike};
 use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Manager};
+
+/// 모니터 틱 간격 (초)
+pub const TICK_INTERVAL_SECS: u64 = 10;
+/// 유휴 이벤트 재발생 최소 간격 (초)
+const IDLE_EVENT_COOLDOWN_SECS: u64 = 5 * 60;
+/// 수면 이벤트 재발생 최소 간격 (초)
+const SLEEP_EVENT_COOLDOWN_SECS: u64 = 10 * 60;
 
 /// 프론트엔드로 보내는 활동 상태
-#[derive(Debug, Clone, Serialize)]
+///
+/// 매 틱이 아니라 바뀐 필드가 있을 때만 보낸다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActivityStatus {
     pub is_ide_running: bool,
+    /// IDE 실행 중 + 최근 입력 있음
+    pub is_coding: bool,
     pub active_ide: Option<String>,
+    /// 실행 중인 모든 IDE / 에디터
+    pub running_ides: Vec<String>,
+    /// 현재 포커스된 앱 (감지 불가 시 null)
+    pub focused_app: Option<String>,
+    /// 작업 중인 등록 저장소 경로
+    pub project: Option<String>,
+    /// 작업 중인 언어
+    pub language: Option<String>,
     pub idle_seconds: u64,
+    /// 직전 보고 대비 바뀐 필드 이름 (첫 보고는 전부)
+    pub changed: Vec<&'static str>,
 }
 
-/// 백그라운드 활동 모니터
-pub async fn start_monitor(app: AppHandle) {
-    let mut interval = tokio::time::interval(Duration::from_secs(10));
-    let mut last_ide_seen = Instant::now();
-    let mut was_ide_running = false;
-    let mut sleep_emitted = false;
+impl ActivityStatus {
+    /// 이전 보고 대비 바뀐 필드 (유휴 시간은 분 단위로 비교)
+    fn changed_fields(&self, prev: &ActivityStatus) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        if self.is_ide_running != prev.is_ide_running {
+            changed.push("isIdeRunning");
+        }
+        if self.is_coding != prev.is_coding {
+            changed.push("isCoding");
+        }
+        if self.active_ide != prev.active_ide {
+            changed.push("activeIde");
+        }
+        if self.running_ides != prev.running_ides {
+            changed.push("runningIdes");
+        }
+        if self.focused_app != prev.focused_app {
+            changed.push("focusedApp");
+        }
+        if self.project != prev.project {
+            changed.push("project");
+        }
+        if self.language != prev.language {
+            changed.push("language");
+        }
+        if self.idle_seconds / 60 != prev.idle_seconds / 60 {
+            changed.push("idleSeconds");
+        }
+        changed
+    }
+}
 
-    loop {
-        interval.tick().await;
+/// 밤 코딩 알림
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LateNightCoding {
+    pub hour: u32,
+    /// 오늘 밤 코딩한 시간 (분)
+    pub minutes: u32,
+    /// 피곤함 단계 (1 = 가벼운 알림, 알릴 때마다 1씩 상승)
+    pub level: u32,
+}
+
+/// 모니터가 갱신하고 커맨드가 조회하는 최신 코딩 상태
+#[derive(Default)]
+pub struct ActivityState(pub Mutex<CodingStatus>);
+
+/// 모니터가 읽는 시계
+pub trait Clock {
+    /// 경과 시간 계산용 단조 시계
+    fn now(&self) -> Instant;
+    /// 밤 시간 판정 / 세션 타임스탬프용 로컬 시각
+    fn local_now(&self) -> DateTime<Local>;
+}
+
+/// 모니터가 읽는 프로세스 / 입력 정보
+pub trait ProcessSource {
+    fn running_ides(&mut self, rules: &IdeRuleSet) -> Vec<RunningIde>;
+    fn focused_app(&mut self, rules: &IdeRuleSet) -> Focus;
+    /// 키보드/마우스 무입력 시간 (알 수 없으면 None)
+    fn input_idle_seconds(&mut self) -> impl Future<Output = Option<u64>> + Send;
+    fn project(&mut self, repos: &[String], pid: Option<u32>, title: Option<&str>) -> Option<String>;
+    fn language(&mut self, pid: Option<u32>, title: Option<&str>, project: Option<&str>) -> Option<String>;
+    /// 포커스된 창이 풀스크린인지
+    fn fullscreen(&mut self) -> bool;
+}
+
+/// 모니터 출력: 이벤트, 상태 머신 입력, 조회용 상태, 코딩 시간 저장
+pub trait MonitorSink {
+    fn emit(&mut self, event: &str, payload: serde_json::Value);
+    fn send_state(&mut self, event: StateEvent);
+    fn set_status(&mut self, status: CodingStatus);
+    /// 코딩 시간 저장 → 실제 적립한 EXP / 레벨업 시 새 레벨
+    fn save_minutes(&mut self, flush: MinuteFlush) -> Result<SavedMinutes, String>;
+    /// 쉬라는 알림 (오래 연속 코딩)
+    fn remind(&mut self, reminder: WellbeingReminder);
+    /// 연속 코딩 뒤 충분히 쉼
+    fn rested(&mut self);
+    /// 풀스크린이면 고양이 창 숨기기, 아니면 다시 보이기
+    fn set_overlay_hidden(&mut self, hidden: bool);
+}
+
+/// 코딩 시간 저장 결과
+#[derive(Debug, Default)]
+pub struct SavedMinutes {
+    /// 실제로 적립한 EXP (하루 한도를 넘긴 시간은 줄어들 수 있음)
+    pub exp: u32,
+    pub level_up: Option<u32>,
+}
+
+/// 한 번에 저장할 코딩 시간
+#[derive(Debug, Clone, Default)]
+pub struct MinuteFlush {
+    pub minutes: u32,
+    pub exp: u32,
+    /// 코딩하지 않고 쉰 시간 (욕구: 기력 회복)
+    pub break_minutes: u32,
+    /// 그중 밤 시간대에 코딩한 시간
+    pub late_night_minutes: u32,
+    pub session: SessionSnapshot,
+    pub projects: Vec<(String, u32)>,
+    pub languages: Vec<(String, u32)>,
+}
+
+/// 실제 시스템 시계
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn local_now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+/// 실제 OS 감지 (`ide_detect`, `idle`, `project`)
+pub struct SystemSource;
+
+impl ProcessSource for SystemSource {
+    fn running_ides(&mut self, rules: &IdeRuleSet) -> Vec<RunningIde> {
+        ide_detect::detect_running_ides(rules)
+    }
+
+    fn focused_app(&mut self, rules: &IdeRuleSet) -> Focus {
+        ide_detect::detect_focused_app(rules)
+    }
+
+    fn input_idle_seconds(&mut self) -> impl Future<Output = Option<u64>> + Send {
+        idle::user_idle_seconds()
+    }
+
+    fn project(&mut self, repos: &[String], pid: Option<u32>, title: Option<&str>) -> Option<String> {
+        project::detect_project(repos, pid, title)
+    }
+
+    fn language(&mut self, pid: Option<u32>, title: Option<&str>, project: Option<&str>) -> Option<String> {
+        ide_detect::detect_language(pid, title, project)
+    }
+
+    fn fullscreen(&mut self) -> bool {
+        ide_detect::detect_fullscreen()
+    }
+}
+
+/// Tauri 앱으로 내보내기 (이벤트 emit, 상태 머신, `ActivityState`, 로컬 저장소)
+pub struct AppSink(pub AppHandle);
+
+impl MonitorSink for AppSink {
+    fn emit(&mut self, event: &str, payload: serde_json::Value) {
+        dnd::emit(&self.0, event, payload);
+    }
+
+    fn send_state(&mut self, event: StateEvent) {
+        self.0.state::<CatStateHandle>().send(event);
+    }
+
+    fn set_status(&mut self, status: CodingStatus) {
+        if let Ok(mut current) = self.0.state::<ActivityState>().0.lock() {
+            *current = status;
+        }
+    }
+
+    /// 오늘 요약 / 고양이 누적 시간 / EXP / 욕구 / 저장소별 시간 / 세션 상태를 한 번에 기록하고 업적을 판정한다.
+    ///
+    /// 고양이 몫은 가장 오래 작업한 저장소와 지금 시각으로 고른 고양이에게 (`cats::credited`).
+    /// 하루 한도 / 연속 밤 코딩도 오늘 요약을 갱신하면서 함께 판정한다.
+    fn save_minutes(&mut self, flush: MinuteFlush) -> Result<SavedMinutes, String> {
+        let now = Local::now();
+        let saved = storage::update(&self.0, |data| {
+            storage::roll_over_day(data);
+            let repo = flush.projects.iter().max_by_key(|(_, added)| *added).map(|(repo, _)| repo.clone());
+            let credited = cats::credited(data, repo.as_deref(), now.hour());
+            let (coding_before, late_night_before) = (data.today.coding_minutes, data.today.late_night_minutes);
+            let exp = wellbeing::healthy_exp(&data.settings, coding_before, flush.minutes, flush.exp);
+            data.session = flush.session;
+            for (repo, added) in flush.projects {
+                *data.today.project_minutes.entry(repo).or_default() += added;
+            }
+            let (level_up, evolved, new_achievements, new_items) = cats::with_cat(data, &credited, |data| {
+                for (language, added) in flush.languages {
+                    *data.today.language_minutes.entry(language.clone()).or_default() += added;
+                    *data.cat.language_minutes.entry(language).or_default() += added;
+                }
+                if flush.break_minutes > 0 {
+                    needs::apply(&mut data.cat.needs, NeedsActivity::Break(flush.break_minutes), now.timestamp());
+                }
+                let mut level_up = None;
+                if flush.minutes > 0 {
+                    data.today.coding_minutes += flush.minutes;
+                    data.today.late_night_minutes += flush.late_night_minutes;
+                    data.today.exp_gained += exp;
+                    data.cat.total_coding_minutes += flush.minutes;
+                    needs::apply(&mut data.cat.needs, NeedsActivity::Coding(flush.minutes), now.timestamp());
+                    growth::touch_streak(data);
+                    level_up = growth::add_exp(&mut data.cat, exp);
+                }
+                let evolved = evolution::evolve(data, now);
+                let new_achievements = achievements::unlock_new(data, now);
+                let new_items = cosmetics::unlock_new(data, now.timestamp());
+                (level_up, evolved, new_achievements, new_items)
+            });
+            let reminders = [
+                wellbeing::daily_limit(&data.settings, coding_before, data.today.coding_minutes),
+                wellbeing::late_night_streak(data, late_night_before),
+            ];
+            Saved {
+                exp,
+                level_up,
+                evolved,
+                needs: data.cat.needs.clone(),
+                new_achievements,
+                new_items,
+                reminders: reminders.into_iter().flatten().collect(),
+            }
+        })?;
+        needs::emit_changed(&self.0, &saved.needs);
+        evolution::emit_evolved(&self.0, &saved.evolved);
+        achievements::emit_unlocked(&self.0, &saved.new_achievements);
+        cosmetics::emit_unlocked(&self.0, &saved.new_items);
+        for reminder in &saved.reminders {
+            wellbeing::remind(&self.0, reminder);
+        }
+        Ok(SavedMinutes {
+            exp: saved.exp,
+            level_up: saved.level_up,
+        })
+    }
+
+    fn remind(&mut self, reminder: WellbeingReminder) {
+        wellbeing::remind(&self.0, &reminder);
+    }
 
-        // 1. IDE 프로세스 감지
-        let detected_ide = detect_running_ide();
+    fn rested(&mut self) {
+        wellbeing::rested(&self.0);
+    }
+
+    fn set_overlay_hidden(&mut self, hidden: bool) {
+        let Some(window) = self.0.get_webview_window("cat-overlay") else {
+            return;
+        };
+        let result = if hidden { window.hide() } else { window.show() };
+        if let Err(e) = result {
+            eprintln!("Failed to toggle cat overlay: {}", e);
+        }
+    }
+}
+
+/// `AppSink::save_minutes`에서 저장한 결과
+struct Saved {
+    exp: u32,
+    level_up: Option<u32>,
+    evolved: Option<EvolutionChanged>,
+    needs: CatNeeds,
+    new_achievements: Vec<Achievement>,
+    new_items: Vec<CosmeticItem>,
+    reminders: Vec<WellbeingReminder>,
+}
+
+/// 밤 코딩 누적 (밤 시간대를 벗어나면 초기화)
+#[derive(Debug, Default)]
+struct LateNightTracker {
+    coding_secs: u64,
+    reminders: u32,
+}
+
+impl LateNightTracker {
+    /// 한 틱 기록 → 알릴 차례면 알림 내용
+    fn record(&mut self, settings: &AppSettings, hour: u32, coding: bool, secs: u64) -> Option<LateNightCoding> {
+        if !settings.is_night_hour(hour) {
+            *self = Self::default();
+            return None;
+        }
+        if !coding {
+            return None;
+        }
+        self.coding_secs += secs;
+        if !settings.late_night_reminders {
+            return None;
+        }
+
+        // 첫 알림 N분 뒤, 이후 반복 간격마다 (간격 0이면 매 분)
+        let minutes = (self.coding_secs / 60) as u32;
+        let due = settings.late_night_reminder_minutes
+            + self.reminders * settings.late_night_repeat_minutes.max(1);
+        if minutes < due {
+            return None;
+        }
+        self.reminders += 1;
+        Some(LateNightCoding { hour, minutes, level: self.reminders })
+    }
+}
+
+/// 이벤트별 마지막 발생 시각 → 쿨다운 안에 같은 이벤트를 다시 보내지 않음
+#[derive(Debug, Default)]
+struct Cooldowns(HashMap<&'static str, Instant>);
+
+impl Cooldowns {
+    /// 보낼 수 있으면 발생 시각을 기록하고 true
+    fn ready(&mut self, event: &'static str, now: Instant, cooldown_secs: u64) -> bool {
+        if let Some(last) = self.0.get(event) {
+            if now.duration_since(*last).as_secs() < cooldown_secs {
+                return false;
+            }
+        }
+        self.0.insert(event, now);
+        true
+    }
+}
+
+/// 활동 모니터 본체
+///
+/// 틱마다 감지 결과로 코딩 여부를 판정하고 이벤트 / 상태 / 코딩 시간을 내보낸다.
+/// 시계, 프로세스 정보, 출력이 모두 주입되므로 OS 없이도 시간을 흘려 보며 검증할 수 있다.
+pub struct Monitor<C, P, S> {
+    clock: C,
+    source: P,
+    sink: S,
+    last_tick: Instant,
+    last_ide_seen: Instant,
+    was_ide_running: bool,
+    was_coding: bool,
+    /// 풀스크린이라 고양이 창을 숨긴 상태
+    overlay_hidden: bool,
+    sleep_emitted: bool,
+    cooldowns: Cooldowns,
+    late_night: LateNightTracker,
+    stretch: StretchTracker,
+    /// 아직 저장하지 않은 휴식 시간 / 밤 코딩 시간 (초)
+    break_secs: u64,
+    night_coding_secs: u64,
+    last_status: Option<ActivityStatus>,
+    session: SessionTracker,
+    project_minutes: MinuteBuckets,
+    language_minutes: MinuteBuckets,
+}
+
+impl<C: Clock, P: ProcessSource, S: MonitorSink> Monitor<C, P, S> {
+    /// 저장된 세션을 이어받아 생성 (공백이 길면 새 세션)
+    pub fn new(clock: C, source: P, sink: S, saved: &SessionSnapshot, settings: &AppSettings) -> Self {
+        let now = clock.now();
+        let session = SessionTracker::restore(
+            saved,
+            clock.local_now().timestamp(),
+            settings.idle_threshold_seconds,
+        );
+        Self {
+            clock,
+            source,
+            sink,
+            last_tick: now,
+            last_ide_seen: now,
+            was_ide_running: false,
+            was_coding: false,
+            overlay_hidden: false,
+            sleep_emitted: false,
+            cooldowns: Cooldowns::default(),
+            late_night: LateNightTracker::default(),
+            stretch: StretchTracker::default(),
+            break_secs: 0,
+            night_coding_secs: 0,
+            last_status: None,
+            session,
+            project_minutes: MinuteBuckets::default(),
+            language_minutes: MinuteBuckets::default(),
+        }
+    }
+
+    /// 한 틱 처리
+    pub async fn tick(&mut self, settings: &AppSettings, rules: &IdeRuleSet) {
+        // 지난 틱 이후 경과 시간 (절전 복귀 등으로 크게 튀면 두 틱 분량만 인정)
+        let now = self.clock.now();
+        let tick_secs = now
+            .duration_since(self.last_tick)
+            .as_secs()
+            .min(2 * TICK_INTERVAL_SECS);
+        self.last_tick = now;
+
+        // 풀스크린이면 고양이 창 숨김 (활동 추적 설정과 무관, 바뀔 때만)
+        let fullscreen = settings.auto_hide_fullscreen && self.source.fullscreen();
+        if fullscreen != self.overlay_hidden {
+            self.overlay_hidden = fullscreen;
+            self.sink.set_overlay_hidden(fullscreen);
+            self.sink.emit(event_names::ACTIVITY_FULLSCREEN, fullscreen.into());
+        }
+
+        // 0. 활동 추적 / IDE 감지가 꺼져 있으면 모니터 일시정지
+        if !settings.activity_tracking || !settings.ide_detection {
+            self.pause(now);
+            return;
+        }
+
+        // 1. IDE 프로세스 감지 + 포커스된 창
+        let running_ides = self.source.running_ides(rules);
+        let running_ide = running_ides.first();
+        let detected_ide = running_ide.map(|ide| ide.name.clone());
         let is_ide_running = detected_ide.is_some();
+        let focus = self.source.focused_app(rules);
+
+        // 코딩 중인 IDE: 포커스 정보가 있으면 IDE가 앞에 있을 때만 인정
+        let coding_ide = match &focus {
+            Focus::Window(app) => app.as_ref().and_then(|app| app.ide.clone()),
+            Focus::Unknown => detected_ide.clone(),
+        };
 
         if is_ide_running {
-            last_ide_seen = Instant::now();
-            sleep_emitted = false;
+            self.last_ide_seen = now;
+        }
+
+        // 유휴 시간 = max(IDE 미감지 시간, 키보드/마우스 무입력 시간)
+        // 입력 유휴를 알 수 없는 OS에서는 IDE 기준만 사용
+        let ide_idle = now.duration_since(self.last_ide_seen).as_secs();
+        let idle_seconds = match self.source.input_idle_seconds().await {
+            Some(input_idle) => ide_idle.max(input_idle),
+            None => ide_idle,
+        };
+        let is_coding = coding_ide.is_some() && idle_seconds < settings.idle_threshold_seconds;
+
+        if is_coding {
+            self.sleep_emitted = false;
+        }
+
+        // 작업 중인 프로젝트 / 언어 → 저장소별, 언어별 코딩 시간
+        let (project, language) = if is_coding {
+            let (pid, title) = match &focus {
+                Focus::Window(Some(app)) => (app.pid, app.title.as_deref()),
+                _ => (running_ide.and_then(|ide| ide.pid), None),
+            };
+            let project = self.source.project(&settings.git_repos, pid, title);
+            let language = self.source.language(pid, title, project.as_deref());
+            (project, language)
+        } else {
+            (None, None)
+        };
+        if let Some(repo) = &project {
+            self.project_minutes.add(repo, tick_secs);
+        }
+        if let Some(language) = &language {
+            self.language_minutes.add(language, tick_secs);
         }
 
-        let idle_seconds = last_ide_seen.elapsed().as_secs();
+        // 세션 누적 → 1분 단위로 오늘 요약 / 누적 시간 / EXP 적립
+        let local_now = self.clock.local_now();
+        self.session.record(
+            is_coding,
+            tick_secs,
+            local_now.timestamp(),
+            settings.idle_threshold_seconds,
+        );
+        if !is_coding {
+            self.break_secs += tick_secs;
+        } else if settings.is_night_hour(local_now.hour()) {
+            self.night_coding_secs += tick_secs;
+        }
+        self.flush_coding_minutes();
 
         // 2. 상태 변화 시에만 이벤트 발생
-        if is_ide_running && !was_ide_running {
+        if is_ide_running && !self.was_ide_running {
             let ide_name = detected_ide.clone().unwrap_or("IDE".to_string());
-            let _ = app.emit("activity:ide-detected", &ide_name);
-        } else if !is_ide_running && was_ide_running {
-            let _ = app.emit("activity:ide-closed", "");
+            self.sink.emit(event_names::ACTIVITY_IDE_DETECTED, ide_name.into());
+        } else if !is_ide_running && self.was_ide_running {
+            self.sink.emit(event_names::ACTIVITY_IDE_CLOSED, "".into());
         }
 
-        // 3. 유휴 시간 체크
-        if idle_seconds >= 600 && !sleep_emitted {
-            let _ = app.emit("activity:sleeping", idle_seconds);
-            sleep_emitted = true;
-        } else if idle_seconds >= 180 && idle_seconds < 600 && was_ide_running && !is_ide_running {
-            let _ = app.emit("activity:idle", idle_seconds);
+        // 3. 유휴 시간 체크 (코딩 → 유휴 전환 시 한 번, 잠들 때 한 번, 각각 쿨다운)
+        let idle_threshold = settings.idle_threshold_seconds;
+        let sleep_threshold = settings.sleep_threshold_seconds;
+        if idle_seconds >= sleep_threshold && !self.sleep_emitted {
+            if self
+                .cooldowns
+                .ready(event_names::ACTIVITY_SLEEPING, now, SLEEP_EVENT_COOLDOWN_SECS)
+            {
+                self.sink.emit(event_names::ACTIVITY_SLEEPING, idle_seconds.into());
+                self.sleep_emitted = true;
+            }
+        } else if idle_seconds >= idle_threshold
+            && idle_seconds < sleep_threshold
+            && self.was_coding
+            && !is_coding
+            && self
+                .cooldowns
+                .ready(event_names::ACTIVITY_IDLE, now, IDLE_EVENT_COOLDOWN_SECS)
+        {
+            self.sink.emit(event_names::ACTIVITY_IDLE, idle_seconds.into());
         }
 
-        // 4. 밤 시간 체크
-        let hour = chrono::Local::now().hour();
-        if is_ide_running && (hour >= 23 || hour < 6) {
-            let _ = app.emit("activity:late-night-coding", hour);
+        // 상태 머신 입력: 코딩 중이면 활동, 아니면 유휴 시간
+        self.sink.send_state(if is_coding {
+            StateEvent::ActivityDetected
+        } else {
+            StateEvent::IdleTimeout(idle_seconds)
+        });
+
+        // 4. 밤 코딩: N분 넘게 이어지면 알리고, 반복 간격마다 피곤함 단계 상승
+        if let Some(reminder) = self
+            .late_night
+            .record(settings, local_now.hour(), is_coding, tick_secs)
+        {
+            self.sink.send_state(StateEvent::LateNightCoding(reminder.minutes));
+            self.sink.emit(
+                event_names::ACTIVITY_LATE_NIGHT_CODING,
+                serde_json::to_value(&reminder).unwrap_or_default(),
+            );
         }
 
-        was_ide_running = is_ide_running;
+        // 5. 연속 코딩: 오래 이어지면 쉬라고 알리고, 충분히 쉬면 초기화
+        match self.stretch.record(settings, is_coding, idle_seconds, tick_secs) {
+            Some(Stretch::Remind(reminder)) => self.sink.remind(reminder),
+            Some(Stretch::Rested) => self.sink.rested(),
+            None => {}
+        }
 
-        // 5. 주기적 상태 보고
-        let status = ActivityStatus {
+        self.was_ide_running = is_ide_running;
+        self.was_coding = is_coding;
+
+        // 6. 주기적 상태 보고
+        self.sink.set_status(CodingStatus {
+            is_coding,
+            active_ide: coding_ide.or_else(|| detected_ide.clone()),
+            idle_seconds,
+            session_minutes: self.session.session_minutes(),
+            project: project.clone(),
+            language: language.clone(),
+            running_ides: running_ides.iter().map(|ide| ide.name.clone()).collect(),
+        });
+        let focused_app = match focus {
+            Focus::Window(app) => app.map(|app| app.name),
+            Focus::Unknown => None,
+        };
+        self.report_status(ActivityStatus {
             is_ide_running,
+            is_coding,
             active_ide: detected_ide,
+            running_ides: running_ides.into_iter().map(|ide| ide.name).collect(),
+            focused_app,
+            project,
+            language,
             idle_seconds,
+            changed: Vec::new(),
+        });
+    }
+
+    /// 직전 보고와 달라진 필드가 있을 때만 `activity:status` 발생
+    fn report_status(&mut self, mut status: ActivityStatus) {
+        status.changed = match &self.last_status {
+            Some(prev) => status.changed_fields(prev),
+            None => vec![
+                "isIdeRunning",
+                "isCoding",
+                "activeIde",
+                "runningIdes",
+                "focusedApp",
+                "project",
+                "language",
+                "idleSeconds",
+            ],
+        };
+        if status.changed.is_empty() {
+            return;
+        }
+        self.sink.emit(
+            event_names::ACTIVITY_STATUS,
+            serde_json::to_value(&status).unwrap_or_default(),
+        );
+        self.last_status = Some(status);
+    }
+
+    /// 감지 중지: 열려 있던 IDE는 닫힌 것으로 알리고 상태 초기화
+    fn pause(&mut self, now: Instant) {
+        if self.was_ide_running {
+            self.sink.emit(event_names::ACTIVITY_IDE_CLOSED, "".into());
+        }
+        self.last_ide_seen = now;
+        self.was_ide_running = false;
+        self.was_coding = false;
+        self.sleep_emitted = false;
+        self.late_night = LateNightTracker::default();
+        self.stretch = StretchTracker::default();
+        self.sink.set_status(CodingStatus::default());
+        self.report_status(ActivityStatus::default());
+    }
+
+    /// 1분 이상 쌓인 코딩 시간을 저장하고 EXP / 레벨업 이벤트 발생
+    fn flush_coding_minutes(&mut self) {
+        let minutes = self.session.take_minutes();
+        let projects = self.project_minutes.take_minutes();
+        let languages = self.language_minutes.take_minutes();
+        let break_minutes = match (self.break_secs / 60) as u32 {
+            minutes if minutes >= BREAK_FLUSH_MINUTES => {
+                self.break_secs %= 60;
+                minutes
+            }
+            _ => 0,
+        };
+        // 밤 코딩 시간은 함께 저장하는 코딩 시간 안에서만
+        let late_night_minutes = ((self.night_coding_secs / 60) as u32).min(minutes);
+        self.night_coding_secs -= late_night_minutes as u64 * 60;
+        if minutes == 0 && break_minutes == 0 && projects.is_empty() && languages.is_empty() {
+            return;
+        }
+
+        let exp = minutes * EXP_PER_CODING_MINUTE;
+        let flush = MinuteFlush {
+            minutes,
+            exp,
+            break_minutes,
+            late_night_minutes,
+            session: self.session.snapshot(),
+            projects,
+            languages,
         };
-        let _ = app.emit("activity:status", &status);
-    }
-}
-
-use chrono::Timelike;
-
-/// IDE 감지 (OS별 분기)
-fn detect_running_ide() -> Option<String> {
-    #[cfg(target_os = "macos")]
-    { detect_running_ide_macos() }
-
-    #[cfg(target_os = "windows")]
-    { detect_running_ide_windows() }
-
-    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
-    { detect_running_ide_unix() }
-}
-
-// ═══════════════════════════════════════
-// macOS: 프로세스 전체 경로로 감지
-// ═══════════════════════════════════════
-#[cfg(target_os = "macos")]
-fn detect_running_ide_macos() -> Option<String> {
-    // ps -A -o args= 로 전체 커맨드라인을 가져옴
-    let output = std::process::Command::new("ps")
-        .args(["-A", "-o", "args="])
-        .output()
-        .ok()?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-
-    // 앱 경로 패턴 → IDE 이름
-    let app_patterns: &[(&str, &str)] = &[
-        // VS Code 계열
-        ("Visual Studio Code.app", "VS Code"),
-        ("Visual Studio Code - Insiders.app", "VS Code Insiders"),
-        ("Cursor.app", "Cursor"),
-        ("Windsurf.app", "Windsurf"),
-        // JetBrains 계열
-        ("IntelliJ IDEA.app", "IntelliJ IDEA"),
-        ("IntelliJ IDEA CE.app", "IntelliJ IDEA"),
-        ("WebStorm.app", "WebStorm"),
-        ("PyCharm.app", "PyCharm"),
-        ("PyCharm CE.app", "PyCharm"),
-        ("GoLand.app", "GoLand"),
-        ("CLion.app", "CLion"),
-        ("RustRover.app", "RustRover"),
-        ("DataGrip.app", "DataGrip"),
-        ("Rider.app", "Rider"),
-        // 기타
-        ("Xcode.app", "Xcode"),
-        ("Zed.app", "Zed"),
-        ("Sublime Text.app", "Sublime Text"),
-        ("Android Studio.app", "Android Studio"),
-    ];
-
-    for (pattern, ide_name) in app_patterns {
-        if stdout.contains(pattern) {
-            return Some(ide_name.to_string());
-        }
-    }
-
-    None
-}
-
-// ═══════════════════════════════════════
-// Windows: tasklist로 감지
-// ═══════════════════════════════════════
-#[cfg(target_os = "windows")]
-fn detect_running_ide_windows() -> Option<String> {
-    let output = std::process::Command::new("tasklist")
-        .args(["/FO", "CSV", "/NH"])
-        .output()
-        .ok()?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-
-    let win_processes: &[(&str, &str)] = &[
-        ("Code.exe", "VS Code"),
-        ("Cursor.exe", "Cursor"),
-        ("devenv.exe", "Visual Studio"),
-        ("idea64.exe", "IntelliJ IDEA"),
-        ("webstorm64.exe", "WebStorm"),
-        ("pycharm64.exe", "PyCharm"),
-        ("goland64.exe", "GoLand"),
-        ("clion64.exe", "CLion"),
-        ("rustrover64.exe", "RustRover"),
-        ("datagrip64.exe", "DataGrip"),
-        ("rider64.exe", "Rider"),
-    ];
-
-    for (proc_name, ide_name) in win_processes {
-        if stdout.contains(proc_name) {
-            return Some(ide_name.to_string());
-        }
-    }
-
-    None
-}
-
-// ═══════════════════════════════════════
-// Linux: ps 기반
-// ═══════════════════════════════════════
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
-fn detect_running_ide_unix() -> Option<String> {
-    let output = std::process::Command::new("ps")
-        .args(["-A", "-o", "args="])
-        .output()
-        .ok()?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-
-    let patterns: &[(&str, &str)] = &[
-        ("/code", "VS Code"),
-        ("/cursor", "Cursor"),
-        ("idea", "IntelliJ IDEA"),
-        ("webstorm", "WebStorm"),
-        ("pycharm", "PyCharm"),
-        ("goland", "GoLand"),
-        ("clion", "CLion"),
-        ("rustrover", "RustRover"),
-        ("sublime_text", "Sublime Text"),
-    ];
-
-    for (pattern, ide_name) in patterns {
-        if stdout.lines().any(|l| l.contains(pattern)) {
-            return Some(ide_name.to_string());
-        }
-    }
-
-    None
+        match self.sink.save_minutes(flush) {
+            Ok(saved) => {
+                if minutes > 0 {
+                    self.sink.emit(
+                        event_names::CAT_EXP_GAINED,
+                        serde_json::json!({ "amount": saved.exp, "source": "coding" }),
+                    );
+                }
+                if let Some(level) = saved.level_up {
+                    self.sink.emit(event_names::CAT_LEVEL_UP, level.into());
+                }
+            }
+            Err(e) => eprintln!("Failed to save coding minutes: {}", e),
+        }
+    }
+}
+
+/// 백그라운드 활동 모니터
+///
+/// 임계값과 밤 시간대는 `AppSettings`에서 읽고, 설정이 바뀌면 즉시 다시 평가한다.
+pub async fn start_monitor(app: AppHandle) {
+    let mut settings_rx = app.state::<SettingsState>().subscribe();
+    let mut interval = tokio::time::interval(Duration::from_secs(TICK_INTERVAL_SECS));
+
+    // 앱 재시작 전 세션 이어가기
+    let saved = storage::load(&app).map(|data| data.session).unwrap_or_default();
+    let settings = settings_rx.borrow().clone();
+    let mut monitor = Monitor::new(SystemClock, SystemSource, AppSink(app.clone()), &saved, &settings);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            changed = settings_rx.changed() => {
+                if changed.is_err() {
+                    return; // 설정 상태가 사라짐 → 앱 종료 중
+                }
+            }
+        }
+        let settings = settings_rx.borrow_and_update().clone();
+        let rules = app.state::<IdeRulesState>().current();
+        monitor.tick(&settings, &rules).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::settings::CatPersistence;
+    use chrono::TimeZone;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// 시뮬레이션 중인 세계: 가상 시각, 실행 중인 IDE, 마지막 입력 시각
+    struct World {
+        base: Instant,
+        base_local: DateTime<Local>,
+        elapsed_secs: u64,
+        ide: Option<String>,
+        /// 마지막 입력 시각 (elapsed 기준, None이면 입력 유휴를 알 수 없음)
+        last_input: Option<u64>,
+        project: Option<String>,
+        language: Option<String>,
+        /// 포커스된 창이 풀스크린
+        fullscreen: bool,
+    }
+
+    type Shared<T> = Rc<RefCell<T>>;
+
+    struct FakeClock(Shared<World>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            let world = self.0.borrow();
+            world.base + Duration::from_secs(world.elapsed_secs)
+        }
+
+        fn local_now(&self) -> DateTime<Local> {
+            let world = self.0.borrow();
+            world.base_local + chrono::Duration::seconds(world.elapsed_secs as i64)
+        }
+    }
+
+    struct FakeSource(Shared<World>);
+
+    impl ProcessSource for FakeSource {
+        fn running_ides(&mut self, _rules: &IdeRuleSet) -> Vec<RunningIde> {
+            let world = self.0.borrow();
+            world.ide.iter().map(|name| RunningIde { name: name.clone(), pid: None }).collect()
+        }
+
+        fn focused_app(&mut self, _rules: &IdeRuleSet) -> Focus {
+            Focus::Unknown
+        }
+
+        fn input_idle_seconds(&mut self) -> impl Future<Output = Option<u64>> + Send {
+            let world = self.0.borrow();
+            let idle = world.last_input.map(|at| world.elapsed_secs - at);
+            std::future::ready(idle)
+        }
+
+        fn project(&mut self, _repos: &[String], _pid: Option<u32>, _title: Option<&str>) -> Option<String> {
+            self.0.borrow().project.clone()
+        }
+
+        fn language(&mut self, _pid: Option<u32>, _title: Option<&str>, _project: Option<&str>) -> Option<String> {
+            self.0.borrow().language.clone()
+        }
+
+        fn fullscreen(&mut self) -> bool {
+            self.0.borrow().fullscreen
+        }
+    }
+
+    /// 내보낸 이벤트 / 상태 / 저장 기록
+    #[derive(Default)]
+    struct Recorded {
+        events: Vec<(String, serde_json::Value)>,
+        status: CodingStatus,
+        states: Vec<StateEvent>,
+        flushes: Vec<MinuteFlush>,
+        cat: CatPersistence,
+        reminders: Vec<WellbeingReminder>,
+        rested: u32,
+        overlay_hidden: bool,
+    }
+
+    impl Recorded {
+        fn count(&self, event: &str) -> usize {
+            self.events.iter().filter(|(name, _)| name == event).count()
+        }
+
+        fn payloads(&self, event: &str) -> Vec<&serde_json::Value> {
+            self.events
+                .iter()
+                .filter(|(name, _)| name == event)
+                .map(|(_, payload)| payload)
+                .collect()
+        }
+
+        fn flushed_minutes(&self) -> u32 {
+            self.flushes.iter().map(|flush| flush.minutes).sum()
+        }
+    }
+
+    struct FakeSink(Shared<Recorded>);
+
+    impl MonitorSink for FakeSink {
+        fn emit(&mut self, event: &str, payload: serde_json::Value) {
+            self.0.borrow_mut().events.push((event.to_string(), payload));
+        }
+
+        fn send_state(&mut self, event: StateEvent) {
+            self.0.borrow_mut().states.push(event);
+        }
+
+        fn set_status(&mut self, status: CodingStatus) {
+            self.0.borrow_mut().status = status;
+        }
+
+        fn save_minutes(&mut self, flush: MinuteFlush) -> Result<SavedMinutes, String> {
+            let mut recorded = self.0.borrow_mut();
+            let exp = flush.exp;
+            let level_up = growth::add_exp(&mut recorded.cat, exp);
+            recorded.flushes.push(flush);
+            Ok(SavedMinutes { exp, level_up })
+        }
+
+        fn remind(&mut self, reminder: WellbeingReminder) {
+            self.0.borrow_mut().reminders.push(reminder);
+        }
+
+        fn rested(&mut self) {
+            self.0.borrow_mut().rested += 1;
+        }
+
+        fn set_overlay_hidden(&mut self, hidden: bool) {
+            self.0.borrow_mut().overlay_hidden = hidden;
+        }
+    }
+
+    struct Harness {
+        world: Shared<World>,
+        recorded: Shared<Recorded>,
+        monitor: Monitor<FakeClock, FakeSource, FakeSink>,
+        settings: AppSettings,
+        rules: IdeRuleSet,
+        runtime: tokio::runtime::Runtime,
+    }
+
+    impl Harness {
+        /// 주어진 로컬 시각에서 시작
+        fn at(hour: u32, minute: u32, saved: SessionSnapshot) -> Self {
+            let base_local = Local
+                .with_ymd_and_hms(2026, 3, 10, hour, minute, 0)
+                .single()
+                .expect("unambiguous local time");
+            let world = Rc::new(RefCell::new(World {
+                base: Instant::now(),
+                base_local,
+                elapsed_secs: 0,
+                ide: None,
+                last_input: None,
+                project: None,
+                language: None,
+                fullscreen: false,
+            }));
+            let recorded = Rc::new(RefCell::new(Recorded::default()));
+            let settings = AppSettings::default();
+            let monitor = Monitor::new(
+                FakeClock(world.clone()),
+                FakeSource(world.clone()),
+                FakeSink(recorded.clone()),
+                &saved,
+                &settings,
+            );
+            Self {
+                world,
+                recorded,
+                monitor,
+                settings,
+                rules: IdeRuleSet::builtin(),
+                runtime: tokio::runtime::Builder::new_current_thread().build().unwrap(),
+            }
+        }
+
+        fn new() -> Self {
+            Self::at(14, 0, SessionSnapshot::default())
+        }
+
+        fn open_ide(&self, name: &str) {
+            self.world.borrow_mut().ide = Some(name.to_string());
+        }
+
+        /// `secs` 동안 틱 진행 (typing이면 매 틱 입력 발생)
+        fn run(&mut self, secs: u64, typing: bool) {
+            for _ in 0..secs / TICK_INTERVAL_SECS {
+                {
+                    let mut world = self.world.borrow_mut();
+                    world.elapsed_secs += TICK_INTERVAL_SECS;
+                    if typing {
+                        world.last_input = Some(world.elapsed_secs);
+                    }
+                }
+                self.runtime.block_on(self.monitor.tick(&self.settings, &self.rules));
+            }
+        }
+
+        fn recorded(&self) -> std::cell::Ref<'_, Recorded> {
+            self.recorded.borrow()
+        }
+
+        fn clear_events(&self) {
+            self.recorded.borrow_mut().events.clear();
+        }
+    }
+
+    #[test]
+    fn an_hour_of_coding_earns_sixty_minutes_of_exp() {
+        let mut h = Harness::new();
+        h.open_ide("VS Code");
+        {
+            let mut world = h.world.borrow_mut();
+            world.project = Some("/home/me/commit-cat".to_string());
+            world.language = Some("Rust".to_string());
+        }
+        h.run(3600, true);
+
+        let recorded = h.recorded();
+        assert_eq!(recorded.flushed_minutes(), 60);
+        let exp: u64 = recorded
+            .payloads(event_names::CAT_EXP_GAINED)
+            .iter()
+            .map(|payload| payload["amount"].as_u64().unwrap())
+            .sum();
+        assert_eq!(exp, 60 * EXP_PER_CODING_MINUTE as u64);
+        let project_minutes: u32 = recorded
+            .flushes
+            .iter()
+            .flat_map(|flush| &flush.projects)
+            .map(|(_, minutes)| minutes)
+            .sum();
+        assert_eq!(project_minutes, 60);
+        let language_minutes: u32 = recorded
+            .flushes
+            .iter()
+            .flat_map(|flush| &flush.languages)
+            .map(|(_, minutes)| minutes)
+            .sum();
+        assert_eq!(language_minutes, 60);
+        assert_eq!(recorded.count(event_names::CAT_LEVEL_UP) as u32, recorded.cat.level - 1);
+        assert!(recorded.status.is_coding);
+        assert_eq!(recorded.status.session_minutes, 60);
+        assert_eq!(recorded.count(event_names::ACTIVITY_IDE_DETECTED), 1);
+    }
+
+    #[test]
+    fn going_idle_emits_idle_then_sleeping_once() {
+        let mut h = Harness::new();
+        h.open_ide("VS Code");
+        h.run(60, true);
+        h.run(30 * 60, false);
+
+        let recorded = h.recorded();
+        assert_eq!(recorded.count(event_names::ACTIVITY_IDLE), 1);
+        assert_eq!(recorded.count(event_names::ACTIVITY_SLEEPING), 1);
+        let idle = recorded.payloads(event_names::ACTIVITY_IDLE)[0].as_u64().unwrap();
+        assert!((300..600).contains(&idle));
+        assert!(!recorded.status.is_coding);
+        // 입력이 끊긴 뒤 유휴 판정(5분) 전까지만 코딩으로 인정
+        assert_eq!(recorded.flushed_minutes(), 5);
+        // 나머지는 휴식 시간으로 5분 이상 모일 때마다 저장
+        let breaks: Vec<u32> = recorded
+            .flushes
+            .iter()
+            .map(|flush| flush.break_minutes)
+            .filter(|&minutes| minutes > 0)
+            .collect();
+        assert!(breaks.iter().all(|&minutes| minutes >= BREAK_FLUSH_MINUTES));
+        assert_eq!(breaks.iter().sum::<u32>(), 25);
+    }
+
+    #[test]
+    fn long_stretches_remind_until_a_real_break() {
+        let mut h = Harness::new();
+        h.open_ide("VS Code");
+        h.run(50 * 60, true);
+        assert_eq!(h.recorded().reminders, vec![WellbeingReminder::LongStretch { minutes: 50 }]);
+
+        // 유휴 판정 전에 돌아오면 이어서 셈
+        h.run(2 * 60, false);
+        h.run(48 * 60, true);
+        assert_eq!(h.recorded().reminders.len(), 2);
+        assert_eq!(h.recorded().rested, 0);
+
+        h.run(10 * 60, false);
+        h.run(30 * 60, true);
+        let recorded = h.recorded();
+        assert_eq!(recorded.rested, 1);
+        assert_eq!(recorded.reminders.len(), 2);
+    }
+
+    #[test]
+    fn fullscreen_hides_the_overlay_until_it_ends() {
+        let mut h = Harness::new();
+        h.run(30, false);
+        assert_eq!(h.recorded().count(event_names::ACTIVITY_FULLSCREEN), 0);
+
+        h.world.borrow_mut().fullscreen = true;
+        h.run(60, false);
+        assert!(h.recorded().overlay_hidden);
+        assert_eq!(h.recorded().payloads(event_names::ACTIVITY_FULLSCREEN), vec![&serde_json::json!(true)]);
+
+        // 설정을 끄면 다시 보임
+        h.settings.auto_hide_fullscreen = false;
+        h.run(30, false);
+        assert!(!h.recorded().overlay_hidden);
+
+        h.settings.auto_hide_fullscreen = true;
+        h.run(30, false);
+        h.world.borrow_mut().fullscreen = false;
+        h.run(30, false);
+        let recorded = h.recorded();
+        assert!(!recorded.overlay_hidden);
+        let toggles: Vec<bool> = recorded
+            .payloads(event_names::ACTIVITY_FULLSCREEN)
+            .iter()
+            .filter_map(|payload| payload.as_bool())
+            .collect();
+        assert_eq!(toggles, vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn closed_ide_counts_as_idle_without_input_tracking() {
+        let mut h = Harness::new();
+        h.open_ide("VS Code");
+        h.run(60, false);
+        assert!(h.recorded().status.is_coding);
+
+        h.world.borrow_mut().ide = None;
+        h.run(20 * 60, false);
+        let recorded = h.recorded();
+        assert_eq!(recorded.count(event_names::ACTIVITY_IDE_CLOSED), 1);
+        assert_eq!(recorded.count(event_names::ACTIVITY_SLEEPING), 1);
+        assert!(!recorded.status.is_coding);
+    }
+
+    #[test]
+    fn state_machine_receives_activity_then_idle_time() {
+        let mut h = Harness::new();
+        h.open_ide("VS Code");
+        h.run(30, true);
+        h.run(10 * 60, false);
+
+        let recorded = h.recorded();
+        assert_eq!(recorded.states[0], StateEvent::ActivityDetected);
+        let last_activity = recorded
+            .states
+            .iter()
+            .rposition(|event| *event == StateEvent::ActivityDetected)
+            .unwrap();
+        assert!(recorded.states[last_activity + 1..]
+            .iter()
+            .all(|event| matches!(event, StateEvent::IdleTimeout(secs) if *secs >= 300)));
+        assert_eq!(recorded.states.last(), Some(&StateEvent::IdleTimeout(600)));
+    }
+
+    #[test]
+    fn idle_is_not_repeated_while_coding_flaps() {
+        let mut h = Harness::new();
+        h.settings.idle_threshold_seconds = 120;
+        h.open_ide("VS Code");
+        h.run(60, true);
+        h.run(130, false);
+        h.run(TICK_INTERVAL_SECS, true);
+        h.run(130, false);
+
+        let recorded = h.recorded();
+        assert_eq!(recorded.count(event_names::ACTIVITY_IDLE), 1);
+        assert!(!recorded.status.is_coding);
+    }
+
+    #[test]
+    fn late_night_reminders_escalate_after_the_configured_minutes() {
+        let mut h = Harness::at(22, 50, SessionSnapshot::default());
+        h.open_ide("VS Code");
+        h.run(10 * 60 - TICK_INTERVAL_SECS, true);
+        assert_eq!(h.recorded().count(event_names::ACTIVITY_LATE_NIGHT_CODING), 0);
+
+        h.run(70 * 60, true);
+        let recorded = h.recorded();
+        let reminders = recorded.payloads(event_names::ACTIVITY_LATE_NIGHT_CODING);
+        let levels: Vec<u64> = reminders.iter().map(|r| r["level"].as_u64().unwrap()).collect();
+        let minutes: Vec<u64> = reminders.iter().map(|r| r["minutes"].as_u64().unwrap()).collect();
+        assert_eq!(levels, vec![1, 2]);
+        assert_eq!(minutes, vec![30, 60]);
+        assert_eq!(reminders[0]["hour"].as_u64(), Some(23));
+        let late_night: Vec<&StateEvent> = recorded
+            .states
+            .iter()
+            .filter(|event| matches!(event, StateEvent::LateNightCoding(_)))
+            .collect();
+        assert_eq!(
+            late_night,
+            vec![&StateEvent::LateNightCoding(30), &StateEvent::LateNightCoding(60)]
+        );
+        // 23시 이후 코딩만 밤 코딩 시간으로 저장 (1분이 안 되는 마지막 자투리는 아직 저장 전)
+        let late_night_minutes: u32 = recorded.flushes.iter().map(|flush| flush.late_night_minutes).sum();
+        assert!((69..=70).contains(&late_night_minutes));
+    }
+
+    #[test]
+    fn late_night_reminders_follow_settings() {
+        let mut h = Harness::at(23, 0, SessionSnapshot::default());
+        h.settings.late_night_reminders = false;
+        h.open_ide("VS Code");
+        h.run(60 * 60, true);
+        assert_eq!(h.recorded().count(event_names::ACTIVITY_LATE_NIGHT_CODING), 0);
+
+        let mut h = Harness::at(1, 0, SessionSnapshot::default());
+        h.settings.night_hour_start = 2;
+        h.settings.late_night_reminder_minutes = 10;
+        h.open_ide("VS Code");
+        h.run(60 * 60, true);
+        assert_eq!(h.recorded().count(event_names::ACTIVITY_LATE_NIGHT_CODING), 0);
+        h.run(15 * 60, true);
+        assert_eq!(h.recorded().count(event_names::ACTIVITY_LATE_NIGHT_CODING), 1);
+    }
+
+    #[test]
+    fn status_is_reported_only_when_it_changes() {
+        let mut h = Harness::new();
+        h.open_ide("VS Code");
+        h.run(10 * 60, true);
+        {
+            let recorded = h.recorded();
+            let statuses = recorded.payloads(event_names::ACTIVITY_STATUS);
+            assert_eq!(statuses.len(), 1);
+            assert_eq!(statuses[0]["changed"].as_array().unwrap().len(), 8);
+        }
+
+        h.clear_events();
+        h.run(2 * 60, false);
+        let recorded = h.recorded();
+        let statuses = recorded.payloads(event_names::ACTIVITY_STATUS);
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses
+            .iter()
+            .all(|status| status["changed"] == serde_json::json!(["idleSeconds"])));
+    }
+
+    #[test]
+    fn paused_monitor_emits_nothing_until_resumed() {
+        let mut h = Harness::new();
+        h.open_ide("VS Code");
+        h.run(60, true);
+        h.clear_events();
+
+        h.settings.ide_detection = false;
+        h.run(10 * 60, true);
+        {
+            let recorded = h.recorded();
+            assert_eq!(recorded.count(event_names::ACTIVITY_IDE_CLOSED), 1);
+            assert_eq!(recorded.count(event_names::ACTIVITY_STATUS), 1);
+            assert_eq!(recorded.events.len(), 2);
+            assert!(!recorded.status.is_coding);
+            assert_eq!(recorded.flushed_minutes(), 1);
+        }
+
+        h.clear_events();
+        h.settings.ide_detection = true;
+        h.run(TICK_INTERVAL_SECS, true);
+        let recorded = h.recorded();
+        assert_eq!(recorded.count(event_names::ACTIVITY_IDE_DETECTED), 1);
+        assert!(recorded.status.is_coding);
+    }
+
+    #[test]
+    fn session_resumes_after_a_short_restart() {
+        let started = Local
+            .with_ymd_and_hms(2026, 3, 10, 14, 0, 0)
+            .single()
+            .unwrap()
+            .timestamp();
+        let saved = SessionSnapshot {
+            session_seconds: 1800,
+            pending_seconds: 30,
+            last_active_at: Some(started - 60),
+        };
+        let mut h = Harness::at(14, 0, saved);
+        h.open_ide("VS Code");
+        h.run(30, true);
+
+        let recorded = h.recorded();
+        assert_eq!(recorded.flushed_minutes(), 1);
+        assert_eq!(recorded.status.session_minutes, 30);
+    }
+
+    #[test]
+    fn session_restarts_after_a_long_gap() {
+        let started = Local
+            .with_ymd_and_hms(2026, 3, 10, 14, 0, 0)
+            .single()
+            .unwrap()
+            .timestamp();
+        let saved = SessionSnapshot {
+            session_seconds: 1800,
+            pending_seconds: 0,
+            last_active_at: Some(started - 3600),
+        };
+        let mut h = Harness::at(14, 0, saved);
+        h.open_ide("VS Code");
+        h.run(30, true);
+
+        assert_eq!(h.recorded().status.session_minutes, 0);
+    }
 }
@@ -0,0 +1,512 @@
+//! 빌드 / 테스트 결과 감지
+//!
+//! - `commit-cat notify`: 셸 훅이나 cargo / npm 래퍼가 종료 코드를 로컬 소켓으로 보낸다
+//! - 등록된 저장소의 JUnit XML 리포트 변경 감시
+//!
+//! 실패하면 고양이가 Frustrated, 같은 프로젝트가 다시 통과하면 축하한다.
+use crate::events::event_names;
+use crate::models::activity::{BuildResult, BuildSource};
+use crate::services::cat_state::CatStateHandle;
+use crate::services::project;
+use crate::services::settings::SettingsState;
+use crate::services::state_machine::StateEvent;
+use crate::services::storage;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 저장소 안에서 찾아볼 JUnit 리포트 (파일 또는 디렉토리)
+const JUNIT_REPORTS: &[&str] = &[
+    "target/nextest",           // cargo nextest
+    "target/surefire-reports",  // Maven
+    "build/test-results",       // Gradle
+    "junit.xml",                // jest-junit, vitest 등
+    "test-results",
+    "reports/junit.xml",
+];
+
+/// 프로젝트별 마지막 빌드 결과 (키: `project_key`)
+#[derive(Default)]
+pub struct BuildState(Mutex<HashMap<String, bool>>);
+
+/// 빌드 결과 처리: 기록 / 오늘 요약 → `build:result` → 상태 머신
+pub fn report(app: &AppHandle, mut result: BuildResult) {
+    let settings = app.state::<SettingsState>().current();
+    if !settings.build_signals {
+        return;
+    }
+
+    let key = result
+        .project
+        .as_deref()
+        .map(|project| project_key(&settings.git_repos, project))
+        .unwrap_or_default();
+    let previous = app
+        .state::<BuildState>()
+        .0
+        .lock()
+        .ok()
+        .and_then(|mut last| last.insert(key, result.success));
+    result.fixed = result.success && previous == Some(false);
+
+    // 최근 실패 비율 (표정)
+    let saved = storage::update(app, |data| {
+        storage::roll_over_day(data);
+        data.today.builds += 1;
+        if !result.success {
+            data.today.build_failures += 1;
+        }
+    });
+    if let Err(e) = saved {
+        eprintln!("Failed to save build result: {}", e);
+    }
+
+    let _ = app.emit(event_names::BUILD_RESULT, &result);
+    let cat_state = app.state::<CatStateHandle>();
+    if !result.success {
+        cat_state.send(StateEvent::ErrorDetected);
+    } else if result.fixed {
+        cat_state.send(StateEvent::BuildFixed);
+    }
+}
+
+/// 실패 → 통과 짝을 맞추는 키
+///
+/// CLI는 작업 디렉토리(하위 폴더일 수 있음)를, JUnit 감시는 등록된 저장소 경로를 보내므로
+/// 감싸는 등록 저장소로 모은다. 등록되지 않은 경로는 정규화한 경로 그대로.
+fn project_key(repos: &[String], project: &str) -> String {
+    let path = Path::new(project);
+    project::match_path(repos, path).unwrap_or_else(|| project::canonical(path).to_string_lossy().to_string())
+}
+
+/// notify 소켓 경로 (CLI와 앱이 같은 규칙으로 찾음)
+///
+/// `COMMIT_CAT_SOCKET` → `$XDG_RUNTIME_DIR/commit-cat/notify.sock` → 임시 디렉토리
+pub fn socket_path() -> PathBuf {
+    if let Some(path) = std::env::var_os("COMMIT_CAT_SOCKET") {
+        return PathBuf::from(path);
+    }
+    match std::env::var_os("XDG_RUNTIME_DIR") {
+        Some(dir) => PathBuf::from(dir).join("commit-cat").join("notify.sock"),
+        None => {
+            let user = std::env::var("USER").unwrap_or_default();
+            std::env::temp_dir()
+                .join(format!("commit-cat-{}", user))
+                .join("notify.sock")
+        }
+    }
+}
+
+/// notify 소켓 수신 (한 줄 = `BuildResult` JSON 하나)
+#[cfg(unix)]
+pub async fn start_listener(app: AppHandle) {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+    use tokio::net::UnixListener;
+
+    let path = socket_path();
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+        let _ = std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700));
+    }
+    match std::os::unix::net::UnixStream::connect(&path) {
+        Ok(_) => {
+            eprintln!("Another instance is already listening on {}", path.display());
+            return;
+        }
+        // 이전 실행이 남긴 소켓 파일 정리 (받는 쪽이 없을 때만)
+        Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => {
+            let _ = std::fs::remove_file(&path);
+        }
+        Err(_) => {}
+    }
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to listen on {}: {}", path.display(), e);
+            return;
+        }
+    };
+    let _ = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600));
+
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                // EMFILE 등 계속되는 에러에서 헛돌지 않도록 잠깐 쉼
+                eprintln!("Failed to accept build signal: {}", e);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                continue;
+            }
+        };
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            // 메시지는 작으므로 64KB로 제한
+            let mut lines = BufReader::new(stream.take(64 * 1024)).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                match serde_json::from_str::<BuildResult>(&line) {
+                    Ok(result) => report(&app, result),
+                    Err(e) => eprintln!("Invalid build signal: {}", e),
+                }
+            }
+        });
+    }
+}
+
+/// `commit-cat notify` 커맨드라인
+///
+/// ```text
+/// commit-cat notify --exit-code <N> [--project <dir>] [--command <cmd>]
+/// commit-cat notify [--project <dir>] -- <command...>   # 실행 후 종료 코드 전달
+/// ```
+///
+/// 반환값은 프로세스 종료 코드 (래핑한 커맨드가 있으면 그 종료 코드)
+pub fn notify_cli(args: &[String]) -> i32 {
+    let NotifyArgs {
+        mut exit_code,
+        project,
+        mut command,
+        wrapped,
+    } = match parse_notify_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("commit-cat notify: {}", e);
+            eprintln!("usage: commit-cat notify --exit-code <N> [--project <dir>] [--command <cmd>]");
+            eprintln!("       commit-cat notify [--project <dir>] -- <command...>");
+            return 2;
+        }
+    };
+
+    // 래핑 모드: 커맨드를 그대로 실행하고 종료 코드를 전달
+    let mut passthrough = 0;
+    if let Some((program, rest)) = wrapped.split_first() {
+        let status = std::process::Command::new(program).args(rest).status();
+        let code = match status {
+            Ok(status) => status.code().unwrap_or(1),
+            Err(e) => {
+                eprintln!("commit-cat notify: failed to run {}: {}", program, e);
+                127
+            }
+        };
+        exit_code = Some(code);
+        passthrough = code;
+        command.get_or_insert_with(|| wrapped.join(" "));
+    }
+
+    let Some(code) = exit_code else {
+        eprintln!("commit-cat notify: --exit-code or -- <command> is required");
+        return 2;
+    };
+    let project = project.or_else(|| {
+        std::env::current_dir()
+            .ok()
+            .map(|dir| dir.to_string_lossy().to_string())
+    });
+
+    let result = BuildResult {
+        success: code == 0,
+        source: BuildSource::Cli,
+        exit_code: Some(code),
+        failures: None,
+        command,
+        project,
+        fixed: false,
+    };
+    if let Err(e) = send(&result) {
+        // 앱이 꺼져 있어도 래핑한 빌드 결과는 그대로 돌려준다
+        eprintln!("commit-cat notify: {}", e);
+    }
+    passthrough
+}
+
+/// `commit-cat notify` 인자
+#[derive(Debug, Default, PartialEq)]
+struct NotifyArgs {
+    exit_code: Option<i32>,
+    project: Option<String>,
+    command: Option<String>,
+    /// `--` 뒤의 래핑할 커맨드
+    wrapped: Vec<String>,
+}
+
+fn parse_notify_args(args: &[String]) -> Result<NotifyArgs, String> {
+    let mut parsed = NotifyArgs::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exit-code" => {
+                let code = args.next().ok_or("--exit-code needs a value")?;
+                let code = code.parse().map_err(|_| format!("invalid exit code {:?}", code))?;
+                parsed.exit_code = Some(code);
+            }
+            "--project" => parsed.project = Some(args.next().ok_or("--project needs a value")?.clone()),
+            "--command" => parsed.command = Some(args.next().ok_or("--command needs a value")?.clone()),
+            "--" => {
+                parsed.wrapped = args.by_ref().cloned().collect();
+            }
+            other => return Err(format!("unknown argument {:?}", other)),
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(unix)]
+fn send(result: &BuildResult) -> Result<(), String> {
+    use std::io::Write;
+    use std::os::unix::net::UnixStream;
+
+    let path = socket_path();
+    let mut stream = UnixStream::connect(&path)
+        .map_err(|e| format!("Commit Cat is not running ({}): {}", path.display(), e))?;
+    let line = serde_json::to_string(result).map_err(|e| e.to_string())?;
+    writeln!(stream, "{}", line).map_err(|e| format!("Failed to send: {}", e))
+}
+
+#[cfg(not(unix))]
+fn send(_result: &BuildResult) -> Result<(), String> {
+    Err("notify is only supported on macOS / Linux".to_string())
+}
+
+/// 등록된 저장소의 JUnit 리포트 감시 (폴링)
+pub async fn start_junit_watcher(app: AppHandle) {
+    let mut interval = tokio::time::interval(Duration::from_secs(15));
+    let mut seen: HashMap<PathBuf, SystemTime> = HashMap::new();
+    // 이미 한 번 훑은 저장소 (처음 훑을 때 있던 리포트는 기준으로만 기록)
+    let mut scanned: HashSet<String> = HashSet::new();
+
+    loop {
+        interval.tick().await;
+
+        let settings = app.state::<SettingsState>().current();
+        if !settings.build_signals {
+            seen.clear();
+            scanned.clear();
+            continue;
+        }
+
+        // 등록 해제된 저장소는 다시 등록되면 처음부터 기준을 잡음
+        scanned.retain(|repo| settings.git_repos.contains(repo));
+        for repo in &settings.git_repos {
+            let baseline = scanned.insert(repo.clone());
+            let mut changed_failures: Option<u32> = None;
+            for report in junit_reports(Path::new(repo)) {
+                let Ok(modified) = std::fs::metadata(&report).and_then(|meta| meta.modified()) else {
+                    continue;
+                };
+                if seen.insert(report.clone(), modified) == Some(modified) {
+                    continue;
+                }
+                // 앱 시작 / 저장소 등록 전에 있던 리포트는 기준으로만 기록
+                if baseline {
+                    continue;
+                }
+                if let Some(failures) = std::fs::read_to_string(&report)
+                    .ok()
+                    .and_then(|xml| junit_failures(&xml))
+                {
+                    *changed_failures.get_or_insert(0) += failures;
+                }
+            }
+
+            if let Some(failures) = changed_failures {
+                report(
+                    &app,
+                    BuildResult {
+                        success: failures == 0,
+                        source: BuildSource::Junit,
+                        exit_code: None,
+                        failures: Some(failures),
+                        command: None,
+                        project: Some(repo.clone()),
+                        fixed: false,
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// 저장소 안의 JUnit XML 파일 (디렉토리는 두 단계까지)
+fn junit_reports(repo: &Path) -> Vec<PathBuf> {
+    fn collect(path: &Path, depth: u32, out: &mut Vec<PathBuf>) {
+        if path.is_file() {
+            if path.extension().is_some_and(|ext| ext == "xml") {
+                out.push(path.to_path_buf());
+            }
+        } else if depth > 0 {
+            if let Ok(entries) = std::fs::read_dir(path) {
+                for entry in entries.flatten() {
+                    collect(&entry.path(), depth - 1, out);
+                }
+            }
+        }
+    }
+
+    let mut reports = Vec::new();
+    for relative in JUNIT_REPORTS {
+        collect(&repo.join(relative), 2, &mut reports);
+    }
+    reports
+}
+
+/// JUnit XML의 실패 + 에러 수 (`<testsuite>`들의 합, 없으면 `<testsuites>` 값)
+///
+/// 중첩된 `<testsuite>`는 바깥 값이 안쪽 합계이므로 가장 안쪽 suite만 센다.
+fn junit_failures(xml: &str) -> Option<u32> {
+    static SUITE: OnceLock<Regex> = OnceLock::new();
+    static SUITES: OnceLock<Regex> = OnceLock::new();
+    static COUNT: OnceLock<Regex> = OnceLock::new();
+    let suite = SUITE.get_or_init(|| Regex::new(r"<(/?)testsuite\b([^>]*?)(/?)>").unwrap());
+    let suites = SUITES.get_or_init(|| Regex::new(r"<testsuites\b([^>]*)>").unwrap());
+    let count = COUNT.get_or_init(|| Regex::new(r#"\b(?:failures|errors)="(\d+)""#).unwrap());
+
+    let sum = |attrs: &str| -> u32 {
+        count
+            .captures_iter(attrs)
+            .filter_map(|c| c[1].parse::<u32>().ok())
+            .sum()
+    };
+
+    // 열린 suite: (실패 수, 안쪽 suite가 있는지)
+    let mut open: Vec<(u32, bool)> = Vec::new();
+    let mut found = false;
+    let mut total = 0;
+    for tag in suite.captures_iter(xml) {
+        let closing = !tag[1].is_empty();
+        let self_closing = !tag[3].is_empty();
+        if closing {
+            if let Some((failures, has_inner)) = open.pop() {
+                if !has_inner {
+                    total += failures;
+                }
+            }
+            continue;
+        }
+
+        found = true;
+        if let Some(parent) = open.last_mut() {
+            parent.1 = true;
+        }
+        if self_closing {
+            total += sum(&tag[2]);
+        } else {
+            open.push((sum(&tag[2]), false));
+        }
+    }
+    // 잘린 파일: 닫히지 않은 suite
+    total += open.iter().filter(|(_, has_inner)| !has_inner).map(|(failures, _)| failures).sum::<u32>();
+
+    if found {
+        return Some(total);
+    }
+    suites.captures(xml).map(|attrs| sum(&attrs[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn notify_args() {
+        let cases: &[(&[&str], Result<NotifyArgs, &str>)] = &[
+            (
+                &["--exit-code", "1", "--project", "/repo", "--command", "cargo test"],
+                Ok(NotifyArgs {
+                    exit_code: Some(1),
+                    project: Some("/repo".into()),
+                    command: Some("cargo test".into()),
+                    wrapped: vec![],
+                }),
+            ),
+            // `--` 뒤는 모두 래핑할 커맨드 (옵션처럼 보여도)
+            (
+                &["--project", "/repo", "--", "cargo", "test", "--", "--exit-code"],
+                Ok(NotifyArgs {
+                    project: Some("/repo".into()),
+                    wrapped: args(&["cargo", "test", "--", "--exit-code"]),
+                    ..Default::default()
+                }),
+            ),
+            (&["--exit-code", "abc"], Err("invalid exit code \"abc\"")),
+            (&["--exit-code"], Err("--exit-code needs a value")),
+            (&["--verbose"], Err("unknown argument \"--verbose\"")),
+        ];
+        for (input, expected) in cases {
+            let parsed = parse_notify_args(&args(input));
+            assert_eq!(parsed.as_ref().map_err(String::as_str), expected.as_ref().map_err(|e| *e), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn notify_cli_rejects_bad_arguments_with_2() {
+        assert_eq!(notify_cli(&args(&["--bogus"])), 2);
+        assert_eq!(notify_cli(&args(&["--exit-code", "one"])), 2);
+        assert_eq!(notify_cli(&args(&["--project", "/repo"])), 2);
+    }
+
+    #[test]
+    fn junit_failure_counts() {
+        let cases: &[(&str, &str, Option<u32>)] = &[
+            ("no suites", "<html></html>", None),
+            (
+                "testsuites only",
+                r#"<testsuites tests="4" failures="1" errors="2"></testsuites>"#,
+                Some(3),
+            ),
+            (
+                "errors + failures per suite",
+                r#"<testsuites><testsuite name="a" failures="1" errors="1"></testsuite><testsuite name="b" failures="2" errors="0"></testsuite></testsuites>"#,
+                Some(4),
+            ),
+            (
+                "self-closing suites",
+                r#"<testsuites failures="9"><testsuite name="a" failures="0" errors="0"/><testsuite name="b" failures="1" errors="0" /></testsuites>"#,
+                Some(1),
+            ),
+            (
+                "nested suites count the innermost only",
+                r#"<testsuite name="outer" failures="3" errors="1">
+                     <testsuite name="inner1" failures="2" errors="0"><testcase/></testsuite>
+                     <testsuite name="inner2" failures="1" errors="1"/>
+                   </testsuite>"#,
+                Some(4),
+            ),
+            (
+                "passing",
+                r#"<?xml version="1.0"?><testsuite name="ok" tests="3" failures="0" errors="0"><testcase name="t"/></testsuite>"#,
+                Some(0),
+            ),
+        ];
+        for (name, xml, expected) in cases {
+            assert_eq!(junit_failures(xml), *expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn project_key_groups_subdirectories_under_the_registered_repo() {
+        let base = std::env::temp_dir().join(format!("commit-cat-build-{}", std::process::id()));
+        let repo = base.join("proj");
+        let sibling = base.join("project2");
+        std::fs::create_dir_all(repo.join("crates/core")).unwrap();
+        std::fs::create_dir_all(&sibling).unwrap();
+        let repo_str = repo.canonicalize().unwrap().to_string_lossy().to_string();
+        let repos = vec![repo_str.clone()];
+
+        // CLI(하위 폴더 cwd)와 JUnit(등록 경로)가 같은 키
+        assert_eq!(project_key(&repos, &repo.join("crates/core").to_string_lossy()), repo_str);
+        assert_eq!(project_key(&repos, &repo_str), repo_str);
+        assert_eq!(project_key(&repos, &format!("{}/crates/..", repo_str)), repo_str);
+        // 이름만 비슷한 옆 폴더는 다른 키
+        assert_ne!(project_key(&repos, &sibling.to_string_lossy()), repo_str);
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
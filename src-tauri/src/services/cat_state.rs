@@ -0,0 +1,155 @@
+use crate::events::event_names;
+use crate::models::cat::{CatState, CatStateChanged};
+use crate::models::settings::CatPersistence;
+use crate::services::dnd;
+use crate::services::pomodoro::PomodoroState;
+use crate::services::settings::SettingsState;
+use crate::services::state_machine::{StateEvent, StateMachine, TransitionContext};
+use crate::services::storage;
+use crate::services::wellbeing::WellbeingState;
+use chrono::Timelike;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+/// 상태 머신 입력 채널 + 현재 상태
+///
+/// 활동 모니터 / Git 감시 / 뽀모도로 / 클릭이 `send`로 이벤트를 넣고,
+/// 러너 하나가 순서대로 `transition`을 적용한다.
+/// 전환 조건에 쓰는 레벨 / 스트릭은 저장할 때마다 갱신해 두고 디스크를 다시 읽지 않는다.
+#[derive(Clone)]
+pub struct CatStateHandle {
+    tx: mpsc::UnboundedSender<StateEvent>,
+    current: Arc<Mutex<CatState>>,
+    progress: Arc<Progress>,
+}
+
+#[derive(Default)]
+struct Progress {
+    level: AtomicU32,
+    streak_days: AtomicU32,
+}
+
+impl CatStateHandle {
+    pub fn new(initial: CatState) -> (Self, mpsc::UnboundedReceiver<StateEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = Self {
+            tx,
+            current: Arc::new(Mutex::new(initial)),
+            progress: Arc::default(),
+        };
+        (handle, rx)
+    }
+
+    pub fn send(&self, event: StateEvent) {
+        let _ = self.tx.send(event);
+    }
+
+    pub fn current(&self) -> CatState {
+        self.current.lock().map(|state| state.clone()).unwrap_or_default()
+    }
+
+    /// 선택된 고양이의 레벨 / 스트릭 반영 (`storage::update`가 저장할 때마다 부름)
+    pub fn set_progress(&self, cat: &CatPersistence) {
+        self.progress.level.store(cat.level, Ordering::Relaxed);
+        self.progress.streak_days.store(cat.streak_days, Ordering::Relaxed);
+    }
+
+    fn set(&self, state: CatState) {
+        if let Ok(mut current) = self.current.lock() {
+            *current = state;
+        }
+    }
+}
+
+/// 상태 머신 러너
+///
+/// 전환이 일어나면 상태를 저장하고 `cat:state-changed`를 보낸다.
+/// 임시 상태(Celebrating / Frustrated / Interaction)는 유지 시간이 지나면 `TimerExpired`를 넣는다.
+pub async fn start_runner(
+    app: AppHandle,
+    handle: CatStateHandle,
+    mut rx: mpsc::UnboundedReceiver<StateEvent>,
+) {
+    let mut deadline: Option<Instant> = None;
+
+    loop {
+        let event = tokio::select! {
+            event = rx.recv() => match event {
+                Some(event) => event,
+                None => return,
+            },
+            _ = wait_until(deadline) => {
+                deadline = None;
+                StateEvent::TimerExpired
+            }
+        };
+
+        let machine = app.state::<StateMachine>();
+        let ctx = transition_context(&app, &handle);
+        let current = handle.current();
+        let Some(next) = machine.transition(&current, &event, &ctx) else {
+            continue;
+        };
+        if next == current {
+            continue;
+        }
+
+        handle.set(next.clone());
+        deadline = machine
+            .timeout_secs(&next)
+            .map(|secs| Instant::now() + Duration::from_secs(secs));
+
+        if let Err(e) = storage::update(&app, |data| data.cat.state = next.clone()) {
+            eprintln!("Failed to save cat state: {}", e);
+        }
+        let _ = app.emit(
+            event_names::CAT_STATE_CHANGED,
+            CatStateChanged {
+                state: next,
+                previous: current,
+            },
+        );
+    }
+}
+
+/// 선택된 고양이가 바뀌면 그 고양이의 저장된 상태로 (임시 상태였으면 시작 상태) 바꾸고 알림
+pub fn reload(app: &AppHandle, saved: &CatState) {
+    let handle = app.state::<CatStateHandle>();
+    let next = app.state::<StateMachine>().restore(saved);
+    let previous = handle.current();
+    if next == previous {
+        return;
+    }
+    handle.set(next.clone());
+    let _ = app.emit(
+        event_names::CAT_STATE_CHANGED,
+        CatStateChanged {
+            state: next,
+            previous,
+        },
+    );
+}
+
+/// 전환 조건: 밤 시간대 / 유휴 기준(설정), 레벨 / 스트릭, 뽀모도로 단계, 휴식 필요 / 방해 금지 여부를 매번 다시 읽음
+fn transition_context(app: &AppHandle, handle: &CatStateHandle) -> TransitionContext {
+    let settings = app.state::<SettingsState>().current();
+    let mut ctx = TransitionContext::from_settings(&settings, chrono::Local::now().hour());
+    ctx.level = handle.progress.level.load(Ordering::Relaxed);
+    ctx.streak_days = handle.progress.streak_days.load(Ordering::Relaxed);
+    ctx.pomodoro = app.state::<PomodoroState>().phase();
+    ctx.needs_break = app.state::<WellbeingState>().needs_break();
+    ctx.quiet = dnd::is_active(app);
+    ctx
+}
+
+/// 복귀 타이머 대기 (타이머가 없으면 영원히 대기)
+async fn wait_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
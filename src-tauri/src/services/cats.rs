@@ -0,0 +1,345 @@
+use crate::models::cat::CatSummary;
+use crate::models::settings::{in_hours, valid_hours, AppData, CatPersistence, CatProfile};
+use crate::services::project;
+use std::path::Path;
+
+/// 고양이 이름 최대 길이
+const MAX_NAME_CHARS: usize = 32;
+
+/// 활동 EXP를 받을 고양이 id
+///
+/// 저장소 경로가 일치하는 고양이 → 시간대가 일치하는 고양이 → 선택된 고양이 순.
+/// 은퇴한 고양이는 건너뛴다. 경로 규칙이 겹치면 더 깊은 경로, 그다음은 목록 순서.
+pub fn credited(data: &AppData, repo: Option<&str>, hour: u32) -> String {
+    let living = || data.cats.iter().filter(|cat| cat.retired_at.is_none());
+    repo.and_then(|repo| {
+        let repo = project::canonical(Path::new(repo));
+        living()
+            .filter_map(|cat| Some((cat, repo_depth(cat, &repo)?)))
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+            .map(|(cat, _)| cat)
+    })
+    .or_else(|| living().find(|cat| cat.hours.is_some_and(|hours| in_hours(hours, hour))))
+    .map_or_else(|| data.active_cat.clone(), |cat| cat.id.clone())
+}
+
+/// 저장소를 포함하는 가장 깊은 경로 규칙의 깊이 (경로 단위 비교, 양쪽 정규화)
+fn repo_depth(cat: &CatProfile, repo: &Path) -> Option<usize> {
+    cat.repos
+        .iter()
+        .filter(|prefix| !prefix.is_empty())
+        .map(|prefix| project::canonical(Path::new(prefix)))
+        .filter(|prefix| repo.starts_with(prefix))
+        .map(|prefix| prefix.components().count())
+        .max()
+}
+
+/// 고양이 하나를 잠시 `data.cat` 자리에 두고 실행
+///
+/// 성장 / 업적 / 진화 로직이 모두 `data.cat`을 보므로, 선택되지 않은 고양이에게
+/// EXP를 줄 때도 같은 코드를 그대로 쓴다. 없는 id면 선택된 고양이로 실행.
+pub fn with_cat<T>(data: &mut AppData, id: &str, f: impl FnOnce(&mut AppData) -> T) -> T {
+    let index = match data.cats.iter().position(|cat| cat.id == id) {
+        Some(index) if id != data.active_cat => index,
+        _ => return f(data),
+    };
+    std::mem::swap(&mut data.cat, &mut data.cats[index].data);
+    let result = f(data);
+    std::mem::swap(&mut data.cat, &mut data.cats[index].data);
+    result
+}
+
+/// 선택된 고양이 프로필
+pub fn active(data: &AppData) -> Option<&CatProfile> {
+    data.cats.iter().find(|cat| cat.id == data.active_cat)
+}
+
+/// 고양이 목록 (선택된 고양이는 `data.cat`의 값으로)
+pub fn summaries(data: &AppData) -> Vec<CatSummary> {
+    data.cats
+        .iter()
+        .map(|profile| {
+            let active = profile.id == data.active_cat;
+            summary(profile, if active { &data.cat } else { &profile.data }, active)
+        })
+        .collect()
+}
+
+/// 새 고양이 (레벨 1부터)
+pub fn create(
+    data: &mut AppData,
+    name: &str,
+    repos: Vec<String>,
+    hours: Option<(u32, u32)>,
+    now: i64,
+) -> Result<CatSummary, String> {
+    let name = valid_name(name)?;
+    if let Some(hours) = hours {
+        valid_hours(hours)?;
+    }
+
+    let base = format!("cat-{}", now);
+    let mut id = base.clone();
+    let mut suffix = 1;
+    while data.cats.iter().any(|cat| cat.id == id) {
+        suffix += 1;
+        id = format!("{}-{}", base, suffix);
+    }
+
+    let profile = CatProfile {
+        id,
+        name,
+        repos: repos.into_iter().map(|repo| repo.trim().to_string()).filter(|repo| !repo.is_empty()).collect(),
+        hours,
+        created_at: Some(now),
+        retired_at: None,
+        data: CatPersistence::default(),
+    };
+    let created = summary(&profile, &profile.data, false);
+    data.cats.push(profile);
+    Ok(created)
+}
+
+/// 이름 바꾸기
+pub fn rename(data: &mut AppData, id: &str, name: &str) -> Result<(), String> {
+    let name = valid_name(name)?;
+    profile_mut(data, id)?.name = name;
+    Ok(())
+}
+
+/// 선택된 고양이 바꾸기 (데이터를 프로필과 `data.cat` 사이에서 맞바꿈)
+pub fn switch(data: &mut AppData, id: &str) -> Result<(), String> {
+    let target = profile_index(data, id)?;
+    if data.cats[target].retired_at.is_some() {
+        return Err(format!("{} is retired", data.cats[target].name));
+    }
+    if id == data.active_cat {
+        return Ok(());
+    }
+
+    let target_data = std::mem::take(&mut data.cats[target].data);
+    let previous = std::mem::replace(&mut data.cat, target_data);
+    if let Some(current) = data.cats.iter_mut().find(|cat| cat.id == data.active_cat) {
+        current.data = previous;
+    }
+    data.active_cat = id.to_string();
+    Ok(())
+}
+
+/// 은퇴 (기록은 남김). 선택된 고양이면 남은 고양이 중 첫 번째로 바꾼다.
+pub fn retire(data: &mut AppData, id: &str, now: i64) -> Result<(), String> {
+    let index = profile_index(data, id)?;
+    if data.cats[index].retired_at.is_some() {
+        return Ok(());
+    }
+    if id == data.active_cat {
+        let next = data
+            .cats
+            .iter()
+            .find(|cat| cat.id != id && cat.retired_at.is_none())
+            .map(|cat| cat.id.clone())
+            .ok_or("Cannot retire the last cat")?;
+        switch(data, &next)?;
+    }
+    data.cats[index].retired_at = Some(now);
+    Ok(())
+}
+
+fn summary(profile: &CatProfile, cat: &CatPersistence, active: bool) -> CatSummary {
+    CatSummary {
+        id: profile.id.clone(),
+        name: profile.name.clone(),
+        level: cat.level,
+        total_exp: cat.total_exp,
+        streak_days: cat.streak_days,
+        evolution: cat.evolution.clone(),
+        repos: profile.repos.clone(),
+        hours: profile.hours,
+        active,
+        retired_at: profile.retired_at,
+    }
+}
+
+fn profile_index(data: &AppData, id: &str) -> Result<usize, String> {
+    data.cats
+        .iter()
+        .position(|cat| cat.id == id)
+        .ok_or_else(|| format!("Unknown cat: {}", id))
+}
+
+fn profile_mut<'a>(data: &'a mut AppData, id: &str) -> Result<&'a mut CatProfile, String> {
+    let index = profile_index(data, id)?;
+    Ok(&mut data.cats[index])
+}
+
+fn valid_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Cat name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_NAME_CHARS {
+        return Err(format!("Cat name must be at most {} characters", MAX_NAME_CHARS));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::settings::DEFAULT_CAT_ID;
+
+    /// (이름, 경로 규칙, 시간대)
+    type Rule<'a> = (&'a str, &'a [&'a str], Option<(u32, u32)>);
+
+    /// 기본 고양이 + 규칙을 가진 고양이들
+    fn data(cats: &[Rule]) -> AppData {
+        let mut data = AppData::default();
+        for (i, (name, repos, hours)) in cats.iter().enumerate() {
+            let repos = repos.iter().map(|repo| repo.to_string()).collect();
+            create(&mut data, name, repos, *hours, i as i64).unwrap();
+        }
+        data
+    }
+
+    fn id_of(data: &AppData, name: &str) -> String {
+        data.cats.iter().find(|cat| cat.name == name).unwrap().id.clone()
+    }
+
+    fn name_of<'a>(data: &'a AppData, id: &str) -> &'a str {
+        &data.cats.iter().find(|cat| cat.id == id).unwrap().name
+    }
+
+    #[test]
+    fn credit_rules() {
+        let data = data(&[
+            ("Proj", &["/home/me/proj"], None),
+            ("Work", &["/work"], Some((9, 18))),
+            ("Client", &["/work/client/"], None),
+            ("Night", &[], Some((22, 6))),
+        ]);
+        let default_name = name_of(&data, DEFAULT_CAT_ID).to_string();
+        // (저장소, 시각, 받는 고양이)
+        let cases: &[(Option<&str>, u32, &str)] = &[
+            (Some("/home/me/proj"), 20, "Proj"),
+            (Some("/home/me/proj/crates/core"), 20, "Proj"),
+            // 문자열 접두사만 같은 옆 저장소는 아님
+            (Some("/home/me/project2"), 20, &default_name),
+            (Some("/home/me/pro"), 20, &default_name),
+            // 겹치는 규칙은 더 깊은 경로가 이김
+            (Some("/work/client/app"), 20, "Client"),
+            (Some("/work/other"), 20, "Work"),
+            // 경로 규칙이 시간대보다 먼저
+            (Some("/work/client/app"), 23, "Client"),
+            // 시간대 (자정을 넘는 구간 포함, 끝 시각은 제외)
+            (None, 9, "Work"),
+            (None, 17, "Work"),
+            (None, 18, &default_name),
+            (None, 22, "Night"),
+            (None, 0, "Night"),
+            (None, 5, "Night"),
+            (None, 6, &default_name),
+            (Some("/elsewhere"), 23, "Night"),
+        ];
+        for (repo, hour, expected) in cases {
+            let id = credited(&data, *repo, *hour);
+            assert_eq!(name_of(&data, &id), *expected, "{:?} at {}", repo, hour);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn credit_rules_compare_canonical_paths() {
+        let base = std::env::temp_dir().join(format!("commit-cat-cats-{}", std::process::id()));
+        let real = base.join("real/proj");
+        std::fs::create_dir_all(real.join("src")).unwrap();
+        std::os::unix::fs::symlink(base.join("real"), base.join("link")).unwrap();
+
+        let rule = base.join("link/proj").to_string_lossy().to_string();
+        let data = data(&[("Proj", &[rule.as_str()], None)]);
+        let repo = real.join("src").to_string_lossy().to_string();
+        assert_eq!(name_of(&data, &credited(&data, Some(&repo), 20)), "Proj");
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn retired_cats_get_no_credit() {
+        let mut data = data(&[("Proj", &["/home/me/proj"], Some((0, 23)))]);
+        let proj = id_of(&data, "Proj");
+        retire(&mut data, &proj, 10).unwrap();
+        assert_eq!(credited(&data, Some("/home/me/proj"), 3), DEFAULT_CAT_ID);
+    }
+
+    #[test]
+    fn hours_are_validated() {
+        let cases = [
+            ((22, 6), true),
+            ((0, 23), true),
+            ((23, 0), true),
+            ((5, 5), false),
+            ((18, 24), true),
+            ((0, 24), true),
+            ((0, 25), false),
+            ((24, 2), false),
+        ];
+        for (hours, ok) in cases {
+            let result = create(&mut AppData::default(), "Cat", vec![], Some(hours), 0);
+            assert_eq!(result.is_ok(), ok, "{:?}", hours);
+        }
+    }
+
+    #[test]
+    fn with_cat_swaps_only_for_other_cats() {
+        let mut data = data(&[("Other", &[], None)]);
+        let other = id_of(&data, "Other");
+
+        cats_level(&mut data, &other, 7);
+        assert_eq!(data.cat.level, 1);
+        assert_eq!(data.cats.iter().find(|cat| cat.id == other).unwrap().data.level, 7);
+
+        // 선택된 고양이 / 없는 id는 `data.cat` 그대로
+        cats_level(&mut data, DEFAULT_CAT_ID, 3);
+        cats_level(&mut data, "missing", 4);
+        assert_eq!(data.cat.level, 4);
+        assert_eq!(data.cats.iter().find(|cat| cat.id == other).unwrap().data.level, 7);
+    }
+
+    fn cats_level(data: &mut AppData, id: &str, level: u32) {
+        with_cat(data, id, |data| data.cat.level = level);
+    }
+
+    #[test]
+    fn switch_moves_data_between_profiles() {
+        let mut data = data(&[("Other", &[], None)]);
+        let other = id_of(&data, "Other");
+        data.cat.level = 9;
+
+        switch(&mut data, &other).unwrap();
+        assert_eq!((data.active_cat.as_str(), data.cat.level), (other.as_str(), 1));
+        switch(&mut data, DEFAULT_CAT_ID).unwrap();
+        assert_eq!(data.cat.level, 9);
+
+        assert_eq!(switch(&mut data, "missing"), Err("Unknown cat: missing".to_string()));
+        retire(&mut data, &other, 10).unwrap();
+        assert_eq!(switch(&mut data, &other), Err("Other is retired".to_string()));
+    }
+
+    #[test]
+    fn retiring_the_active_cat_switches_to_the_next_living_cat() {
+        let mut data = data(&[("Gone", &[], None), ("Next", &[], None)]);
+        let (gone, next) = (id_of(&data, "Gone"), id_of(&data, "Next"));
+        retire(&mut data, &gone, 5).unwrap();
+        data.cat.level = 12;
+
+        retire(&mut data, DEFAULT_CAT_ID, 20).unwrap();
+        assert_eq!(data.active_cat, next);
+        let retired = data.cats.iter().find(|cat| cat.id == DEFAULT_CAT_ID).unwrap();
+        assert_eq!((retired.retired_at, retired.data.level), (Some(20), 12));
+
+        // 두 번 은퇴해도 기록은 처음 그대로, 마지막 고양이는 은퇴 불가
+        retire(&mut data, &gone, 30).unwrap();
+        assert_eq!(data.cats.iter().find(|cat| cat.id == gone).unwrap().retired_at, Some(5));
+        assert_eq!(retire(&mut data, &next, 40), Err("Cannot retire the last cat".to_string()));
+        assert_eq!(data.active_cat, next);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::events::event_names;
+use crate::models::cosmetic::{
+    CosmeticDef, CosmeticItem, CosmeticSlot, Inventory, Unlock, UnlockCondition, COSMETICS, DEFAULT_FUR,
+};
+use crate::models::settings::{AppData, CatPersistence};
+use crate::services::dnd;
+use tauri::AppHandle;
+
+/// 레벨 / 업적 조건을 새로 만족한 아이템을 해금 기록에 남기고 반환
+///
+/// 업적 판정(`achievements::unlock_new`) 다음에 불러 같은 저장에서 업적 보상까지 반영한다.
+pub fn unlock_new(data: &mut AppData, now: i64) -> Vec<CosmeticItem> {
+    let mut unlocked = Vec::new();
+    for def in COSMETICS {
+        if data.cat.items.contains_key(def.id) || !meets(&data.cat, def) {
+            continue;
+        }
+        data.cat.items.insert(def.id.to_string(), now);
+        unlocked.push(item(&data.cat, def));
+    }
+    unlocked
+}
+
+/// 전체 아이템 + 착용 상태
+pub fn inventory(cat: &CatPersistence) -> Inventory {
+    Inventory {
+        items: COSMETICS.iter().map(|def| item(cat, def)).collect(),
+        equipped: cat.equipped.clone(),
+    }
+}
+
+/// 자리에 아이템 착용 (None이면 벗기, 털 색은 기본값으로)
+pub fn equip(cat: &mut CatPersistence, slot: CosmeticSlot, item_id: Option<&str>) -> Result<(), String> {
+    if let Some(id) = item_id {
+        let def = COSMETICS
+            .iter()
+            .find(|def| def.id == id)
+            .ok_or_else(|| format!("Unknown item: {}", id))?;
+        if def.slot != slot {
+            return Err(format!("{} cannot be worn as {:?}", id, slot));
+        }
+        if !cat.items.contains_key(id) && !meets(cat, def) {
+            return Err(format!("{} is still locked", id));
+        }
+    }
+
+    let equipped = &mut cat.equipped;
+    let item_id = item_id.map(str::to_string);
+    match slot {
+        CosmeticSlot::Hat => equipped.hat = item_id,
+        CosmeticSlot::Collar => equipped.collar = item_id,
+        CosmeticSlot::Accessory => equipped.accessory = item_id,
+        CosmeticSlot::Fur => equipped.fur = item_id.unwrap_or_else(|| DEFAULT_FUR.to_string()),
+    }
+    Ok(())
+}
+
+/// 해금마다 `cat:item-unlocked` 발생
+pub fn emit_unlocked(app: &AppHandle, unlocked: &[CosmeticItem]) {
+    for item in unlocked {
+        dnd::emit(app, event_names::CAT_ITEM_UNLOCKED, item);
+    }
+}
+
+fn meets(cat: &CatPersistence, def: &CosmeticDef) -> bool {
+    match def.unlock {
+        Unlock::Level(level) => cat.level >= level,
+        Unlock::Achievement(id) => cat.achievements.contains_key(id),
+    }
+}
+
+fn item(cat: &CatPersistence, def: &CosmeticDef) -> CosmeticItem {
+    let equipped = &cat.equipped;
+    CosmeticItem {
+        id: def.id.to_string(),
+        name: def.name.to_string(),
+        slot: def.slot,
+        icon: def.icon.to_string(),
+        unlock: match def.unlock {
+            Unlock::Level(level) => UnlockCondition::Level { level },
+            Unlock::Achievement(id) => UnlockCondition::Achievement { id: id.to_string() },
+        },
+        unlocked_at: cat.items.get(def.id).copied(),
+        equipped: [
+            equipped.hat.as_deref(),
+            equipped.collar.as_deref(),
+            Some(equipped.fur.as_str()),
+            equipped.accessory.as_deref(),
+        ]
+        .contains(&Some(def.id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cat(level: u32, achievements: &[&str]) -> CatPersistence {
+        let mut cat = CatPersistence {
+            level,
+            ..CatPersistence::default()
+        };
+        for id in achievements {
+            cat.achievements.insert(id.to_string(), 0);
+        }
+        cat
+    }
+
+    fn unlocked_ids(data: &mut AppData) -> Vec<String> {
+        unlock_new(data, 100).into_iter().map(|item| item.id).collect()
+    }
+
+    #[test]
+    fn level_and_achievements_unlock_items_once() {
+        let mut data = AppData {
+            cat: cat(4, &["first-commit"]),
+            ..AppData::default()
+        };
+        assert_eq!(
+            unlocked_ids(&mut data),
+            vec!["fur-orange", "fur-gray", "hat-cap", "collar-ribbon", "collar-bell"]
+        );
+        assert_eq!(data.cat.items.get("hat-cap"), Some(&100));
+        assert!(unlocked_ids(&mut data).is_empty());
+
+        // 레벨업 / 업적 해금 뒤 새로 만족한 것만
+        data.cat.level = 5;
+        data.cat.achievements.insert("streak-7".to_string(), 0);
+        assert_eq!(unlocked_ids(&mut data), vec!["fur-black", "acc-flower"]);
+    }
+
+    #[test]
+    fn equip_rejects_unknown_locked_and_wrong_slot_items() {
+        let mut cat = cat(2, &[]);
+        let cases = [
+            (CosmeticSlot::Hat, "hat-wizard", "Unknown item: hat-wizard"),
+            (CosmeticSlot::Hat, "hat-top", "hat-top is still locked"),
+            (CosmeticSlot::Collar, "collar-ribbon", "collar-ribbon is still locked"),
+            (CosmeticSlot::Collar, "hat-cap", "hat-cap cannot be worn as Collar"),
+        ];
+        for (slot, id, error) in cases {
+            assert_eq!(equip(&mut cat, slot, Some(id)), Err(error.to_string()));
+        }
+        assert_eq!(cat.equipped, CatPersistence::default().equipped);
+    }
+
+    #[test]
+    fn equip_keeps_one_item_per_slot() {
+        let mut cat = cat(15, &["first-commit"]);
+        equip(&mut cat, CosmeticSlot::Hat, Some("hat-cap")).unwrap();
+        equip(&mut cat, CosmeticSlot::Collar, Some("collar-ribbon")).unwrap();
+        equip(&mut cat, CosmeticSlot::Hat, Some("hat-top")).unwrap();
+        assert_eq!(cat.equipped.hat.as_deref(), Some("hat-top"));
+        assert_eq!(cat.equipped.collar.as_deref(), Some("collar-ribbon"));
+
+        let worn: Vec<String> = inventory(&cat)
+            .items
+            .into_iter()
+            .filter(|item| item.equipped)
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(worn, vec!["fur-orange", "hat-top", "collar-ribbon"]);
+
+        // 벗기: 털 색은 기본값으로
+        equip(&mut cat, CosmeticSlot::Hat, None).unwrap();
+        equip(&mut cat, CosmeticSlot::Fur, Some("fur-white")).unwrap();
+        equip(&mut cat, CosmeticSlot::Fur, None).unwrap();
+        assert_eq!(cat.equipped.hat, None);
+        assert_eq!(cat.equipped.fur, DEFAULT_FUR);
+    }
+
+    #[test]
+    fn unlocked_items_stay_wearable() {
+        // 해금 기록에 남은 아이템은 조건을 다시 보지 않는다
+        let mut cat = cat(1, &[]);
+        cat.items.insert("hat-top".to_string(), 0);
+        assert_eq!(equip(&mut cat, CosmeticSlot::Hat, Some("hat-top")), Ok(()));
+    }
+}
@@ -0,0 +1,384 @@
+//! 방해 금지 (직접 켜기 / 조용한 시간 / 뽀모도로 집중 / 화면 공유)
+//!
+//! 켜져 있는 동안 시스템 알림과 축하 이벤트(레벨업, 업적 등)는 보내지 않고 모아 두었다가
+//! 끝나면 순서대로 보낸다. 고양이의 축하 전환은 상태 머신 조건 `quiet`로 막는다.
+use crate::events::event_names;
+use crate::models::behavior::PomodoroPhase;
+use crate::models::dnd::{DndReason, DndStatus};
+use crate::services::ide_detect;
+use crate::services::pomodoro::PomodoroState;
+use crate::services::settings::SettingsState;
+use chrono::Timelike;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// 조용한 시간 / 화면 공유를 다시 확인하는 간격 (초)
+pub const CHECK_INTERVAL_SECS: u64 = 30;
+/// 모아 두는 최대 개수 (넘치면 오래된 것부터 버림)
+const MAX_QUEUED: usize = 50;
+
+/// 방해 금지 중에는 모아 두는 이벤트 (급하지 않은 축하 / 알림)
+const DEFERRED_EVENTS: &[&str] = &[
+    event_names::CAT_LEVEL_UP,
+    event_names::CAT_EVOLVED,
+    event_names::CAT_ACHIEVEMENT_UNLOCKED,
+    event_names::CAT_ITEM_UNLOCKED,
+    event_names::ACTIVITY_LATE_NIGHT_CODING,
+    event_names::WELLBEING_REMINDER,
+];
+
+/// 방해 금지 상태 + 모아 둔 알림
+#[derive(Default)]
+pub struct DndState(Mutex<Dnd>);
+
+#[derive(Default)]
+struct Dnd {
+    manual: bool,
+    /// 직접 켠 방해 금지가 끝나는 시각 (unix 초)
+    manual_until: Option<i64>,
+    /// 마지막 확인에서 찾은 화면 공유 앱
+    sharing_app: Option<String>,
+    /// 마지막으로 알린 켜짐 여부
+    was_active: bool,
+    queue: VecDeque<Queued>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Queued {
+    Event { name: String, payload: serde_json::Value },
+    Notification { title: String, body: String },
+}
+
+impl Dnd {
+    /// 직접 켜기 / 끄기 (minutes가 있으면 now부터 그 시간 뒤 자동으로 꺼짐)
+    fn set_manual(&mut self, enabled: bool, minutes: Option<u32>, now: i64) {
+        self.manual = enabled;
+        self.manual_until = minutes.filter(|_| enabled).map(|minutes| now + minutes as i64 * 60);
+    }
+
+    /// 직접 켠 방해 금지가 아직 유효한지
+    fn manual_on(&self, now: i64) -> bool {
+        self.manual && self.manual_until.is_none_or(|until| now < until)
+    }
+
+    /// 끝나는 시각이 지났으면 직접 켠 방해 금지 해제
+    fn expire_manual(&mut self, now: i64) {
+        if !self.manual_on(now) {
+            self.manual = false;
+            self.manual_until = None;
+        }
+    }
+
+    /// 모아 두기 (넘치면 가장 오래된 것부터 버림)
+    fn push(&mut self, item: Queued) {
+        if self.queue.len() >= MAX_QUEUED {
+            self.queue.pop_front();
+        }
+        self.queue.push_back(item);
+    }
+
+    /// 판정 결과 반영 → (켜짐 여부가 바뀌었는지, 꺼졌으면 모아 둔 것 전부 순서대로)
+    fn settle(&mut self, active: bool) -> (bool, Vec<Queued>) {
+        let changed = active != self.was_active;
+        self.was_active = active;
+        let queued = if active { Vec::new() } else { self.queue.drain(..).collect() };
+        (changed, queued)
+    }
+}
+
+/// 지금 방해 금지 중인지 (상태 머신 조건 / 이벤트 보류 판단)
+pub fn is_active(app: &AppHandle) -> bool {
+    let state = app.state::<DndState>();
+    let Ok(dnd) = state.0.lock() else {
+        return false;
+    };
+    !reasons(app, &dnd).is_empty()
+}
+
+/// 이벤트 보내기 (방해 금지 중이면 급하지 않은 이벤트는 모아 둠)
+pub fn emit<S: Serialize + Clone>(app: &AppHandle, event: &str, payload: S) {
+    let deferred = DEFERRED_EVENTS.contains(&event)
+        && defer(app, || Queued::Event {
+            name: event.to_string(),
+            payload: serde_json::to_value(&payload).unwrap_or_default(),
+        });
+    if deferred {
+        return;
+    }
+    let _ = app.emit(event, payload);
+}
+
+/// 시스템 알림 (방해 금지 중이면 모아 둠)
+pub fn notify(app: &AppHandle, title: &str, body: &str) {
+    let deferred = defer(app, || Queued::Notification {
+        title: title.to_string(),
+        body: body.to_string(),
+    });
+    if deferred {
+        return;
+    }
+    show(app, title, body);
+}
+
+/// 직접 켜기 / 끄기 (minutes가 있으면 그 시간 뒤 자동으로 꺼짐)
+pub fn set_manual(app: &AppHandle, enabled: bool, minutes: Option<u32>) -> DndStatus {
+    if let Ok(mut dnd) = app.state::<DndState>().0.lock() {
+        dnd.set_manual(enabled, minutes, chrono::Local::now().timestamp());
+    }
+    refresh(app)
+}
+
+/// 현재 상태
+pub fn status(app: &AppHandle) -> DndStatus {
+    let state = app.state::<DndState>();
+    let Ok(dnd) = state.0.lock() else {
+        return DndStatus::default();
+    };
+    status_of(app, &dnd)
+}
+
+/// 다시 판정 → 바뀌었으면 `dnd:changed`, 끝났으면 모아 둔 알림 보내기
+///
+/// 뽀모도로 시작 / 종료처럼 이유가 바뀌는 곳에서 바로 부른다.
+pub fn refresh(app: &AppHandle) -> DndStatus {
+    let (status, changed, queued) = {
+        let state = app.state::<DndState>();
+        let Ok(mut dnd) = state.0.lock() else {
+            return DndStatus::default();
+        };
+        dnd.expire_manual(chrono::Local::now().timestamp());
+        let status = status_of(app, &dnd);
+        let (changed, queued) = dnd.settle(status.active);
+        (status, changed, queued)
+    };
+
+    if changed {
+        let _ = app.emit(event_names::DND_CHANGED, &status);
+    }
+    for item in queued {
+        match item {
+            Queued::Event { name, payload } => {
+                let _ = app.emit(&name, payload);
+            }
+            Queued::Notification { title, body } => show(app, &title, &body),
+        }
+    }
+    status
+}
+
+/// 주기적으로 화면 공유 앱 / 조용한 시간을 확인 (설정이 바뀌면 바로)
+pub async fn start_watcher(app: AppHandle) {
+    let mut settings_rx = app.state::<SettingsState>().subscribe();
+    let mut interval = tokio::time::interval(Duration::from_secs(CHECK_INTERVAL_SECS));
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            changed = settings_rx.changed() => {
+                if changed.is_err() {
+                    return; // 설정 상태가 사라짐 → 앱 종료 중
+                }
+            }
+        }
+
+        let settings = settings_rx.borrow_and_update().clone();
+        let sharing_app = if settings.dnd_while_screen_sharing {
+            detect_screen_sharing(&settings.screen_sharing_apps, &settings.screen_sharing_titles)
+        } else {
+            None
+        };
+        if let Ok(mut dnd) = app.state::<DndState>().0.lock() {
+            dnd.sharing_app = sharing_app;
+        }
+        refresh(&app);
+    }
+}
+
+/// 화면 공유 / 화상 회의: 열린 창 제목으로 판단
+fn detect_screen_sharing(apps: &[String], titles: &[String]) -> Option<String> {
+    if titles.iter().all(String::is_empty) {
+        return None;
+    }
+    sharing_app(
+        apps,
+        titles,
+        &ide_detect::running_process_names(),
+        &ide_detect::window_titles(),
+    )
+}
+
+/// 공유 중 표시 창(제목 일부 일치)이 있어야 공유 중으로 본다.
+/// 회의 앱이 켜져 있기만 한 것은 공유가 아님 → 프로세스 이름은 표시 이름으로만 쓴다.
+fn sharing_app(apps: &[String], titles: &[String], running: &[String], windows: &[String]) -> Option<String> {
+    let windows: Vec<String> = windows.iter().map(|title| title.to_lowercase()).collect();
+    let fragment = titles.iter().find(|fragment| {
+        !fragment.is_empty() && windows.iter().any(|title| title.contains(&fragment.to_lowercase()))
+    })?;
+    let by_process = apps
+        .iter()
+        .find(|app_name| running.iter().any(|name| name.eq_ignore_ascii_case(app_name)));
+    Some(by_process.unwrap_or(fragment).clone())
+}
+
+fn reasons(app: &AppHandle, dnd: &Dnd) -> Vec<DndReason> {
+    let settings = app.state::<SettingsState>().current();
+    let now = chrono::Local::now();
+    [
+        (DndReason::Manual, dnd.manual_on(now.timestamp())),
+        (DndReason::QuietHours, settings.is_quiet_hour(now.hour())),
+        (
+            DndReason::Pomodoro,
+            settings.dnd_during_pomodoro && app.state::<PomodoroState>().phase() == PomodoroPhase::Work,
+        ),
+        (
+            DndReason::ScreenSharing,
+            settings.dnd_while_screen_sharing && dnd.sharing_app.is_some(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, on)| *on)
+    .map(|(reason, _)| reason)
+    .collect()
+}
+
+fn status_of(app: &AppHandle, dnd: &Dnd) -> DndStatus {
+    let reasons = reasons(app, dnd);
+    DndStatus {
+        active: !reasons.is_empty(),
+        reasons,
+        manual_until: dnd.manual_until.filter(|_| dnd.manual),
+        sharing_app: dnd.sharing_app.clone(),
+        queued: dnd.queue.len(),
+    }
+}
+
+/// 방해 금지 중이면 모아 둠 (판정과 저장을 같은 잠금 안에서 → 그사이 `refresh`가 비우지 못함)
+fn defer(app: &AppHandle, item: impl FnOnce() -> Queued) -> bool {
+    let state = app.state::<DndState>();
+    let Ok(mut dnd) = state.0.lock() else {
+        return false;
+    };
+    if reasons(app, &dnd).is_empty() {
+        return false;
+    }
+    dnd.push(item());
+    true
+}
+
+fn show(app: &AppHandle, title: &str, body: &str) {
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        eprintln!("Failed to show notification: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(n: usize) -> Queued {
+        Queued::Event {
+            name: event_names::CAT_LEVEL_UP.to_string(),
+            payload: serde_json::json!(n),
+        }
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn queue_drains_in_order_only_when_dnd_ends() {
+        let mut dnd = Dnd::default();
+        assert_eq!(dnd.settle(true), (true, vec![]));
+
+        dnd.push(event(1));
+        dnd.push(Queued::Notification {
+            title: "Break".to_string(),
+            body: "Stretch".to_string(),
+        });
+        dnd.push(event(2));
+        assert_eq!(dnd.settle(true), (false, vec![]));
+        assert_eq!(dnd.queue.len(), 3);
+
+        let (changed, queued) = dnd.settle(false);
+        assert!(changed);
+        assert_eq!(
+            queued,
+            vec![
+                event(1),
+                Queued::Notification {
+                    title: "Break".to_string(),
+                    body: "Stretch".to_string(),
+                },
+                event(2),
+            ]
+        );
+        assert!(dnd.queue.is_empty());
+        assert_eq!(dnd.settle(false), (false, vec![]));
+    }
+
+    #[test]
+    fn full_queue_drops_the_oldest() {
+        let mut dnd = Dnd::default();
+        for n in 0..MAX_QUEUED + 2 {
+            dnd.push(event(n));
+        }
+        let (_, queued) = dnd.settle(false);
+        assert_eq!(queued.len(), MAX_QUEUED);
+        assert_eq!(queued.first(), Some(&event(2)));
+        assert_eq!(queued.last(), Some(&event(MAX_QUEUED + 1)));
+    }
+
+    #[test]
+    fn manual_until_expires() {
+        let now = 1_000_000;
+        let mut dnd = Dnd::default();
+
+        dnd.set_manual(true, Some(30), now);
+        assert_eq!(dnd.manual_until, Some(now + 30 * 60));
+        assert!(dnd.manual_on(now + 30 * 60 - 1));
+        dnd.expire_manual(now + 30 * 60 - 1);
+        assert!(dnd.manual);
+
+        assert!(!dnd.manual_on(now + 30 * 60));
+        dnd.expire_manual(now + 30 * 60);
+        assert!(!dnd.manual);
+        assert_eq!(dnd.manual_until, None);
+
+        // 시간 없이 켜면 끌 때까지, 끄면 시간도 지움
+        dnd.set_manual(true, None, now);
+        dnd.expire_manual(i64::MAX);
+        assert!(dnd.manual_on(i64::MAX));
+        dnd.set_manual(false, Some(30), now);
+        assert!(!dnd.manual_on(now));
+        assert_eq!(dnd.manual_until, None);
+    }
+
+    #[test]
+    fn screen_sharing_needs_a_sharing_window() {
+        let apps = strings(&["zoom", "teams"]);
+        let titles = strings(&["is sharing your screen", "You are screen sharing", ""]);
+        // (실행 중인 프로세스, 열린 창 제목, 결과)
+        let cases: &[(&[&str], &[&str], Option<&str>)] = &[
+            // 회의 앱이 켜져 있기만 하면 공유가 아님
+            (&["zoom"], &["Zoom Meeting", "main.rs - Code"], None),
+            (&["Zoom"], &["Zoom Meeting", "you are SCREEN SHARING"], Some("zoom")),
+            // 브라우저 회의: 앱 이름이 없으면 일치한 제목으로
+            (&["firefox"], &["meet.google.com is sharing your screen."], Some("is sharing your screen")),
+            (&["firefox"], &["Google Meet"], None),
+            (&[], &[], None),
+        ];
+        for (running, windows, expected) in cases {
+            assert_eq!(
+                sharing_app(&apps, &titles, &strings(running), &strings(windows)),
+                expected.map(str::to_string),
+                "{:?} {:?}",
+                running,
+                windows
+            );
+        }
+    }
+}
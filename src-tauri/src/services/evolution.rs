@@ -0,0 +1,223 @@
+use crate::events::event_names;
+use crate::models::evolution::{BranchScores, Evolution, EvolutionBranch, EvolutionChanged, LifeStage};
+use crate::models::settings::AppData;
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
+use crate::services::dnd;
+use tauri::AppHandle;
+
+/// 갈래를 정할 때 보는 기간: 직전 진화 이후, 최대 30일
+pub const BRANCH_WINDOW_DAYS: i64 = 30;
+
+/// 갈래 기준치 (이만큼이면 점수 1.0)
+/// 코딩 1시간당 커밋 2개 / 코딩 시간의 절반이 뽀모도로 / 코딩 시간의 25%가 밤
+const COMMITS_PER_HOUR: f32 = 2.0;
+const FOCUS_RATIO: f32 = 0.5;
+const NIGHT_RATIO: f32 = 0.25;
+const MINUTES_PER_POMODORO: f32 = 25.0;
+
+/// 레벨이 다음 단계 기준을 넘었으면 진화 (갈래는 직전 기간 활동으로)
+///
+/// 레벨업을 저장하는 쪽(`storage::update`) 안에서 `growth::add_exp` 다음에 부른다.
+pub fn evolve(data: &mut AppData, now: DateTime<Local>) -> Option<EvolutionChanged> {
+    let stage = LifeStage::for_level(data.cat.level);
+    let previous = data.cat.evolution.stage;
+    if stage <= previous {
+        return None;
+    }
+
+    let scores = scores(data, now.date_naive());
+    data.cat.evolution = Evolution {
+        stage,
+        branch: Some(branch(&scores)),
+        evolved_at: Some(now.timestamp()),
+    };
+    Some(EvolutionChanged {
+        previous,
+        evolution: data.cat.evolution.clone(),
+        scores,
+    })
+}
+
+/// 진화했으면 `cat:evolved` 발생
+pub fn emit_evolved(app: &AppHandle, changed: &Option<EvolutionChanged>) {
+    if let Some(changed) = changed {
+        dnd::emit(app, event_names::CAT_EVOLVED, changed);
+    }
+}
+
+/// 직전 진화 이후(최대 30일) 활동의 갈래별 점수
+pub fn scores(data: &AppData, today: NaiveDate) -> BranchScores {
+    let window_start = today - Duration::days(BRANCH_WINDOW_DAYS - 1);
+    let start = data
+        .cat
+        .evolution
+        .evolved_at
+        .and_then(|at| Local.timestamp_opt(at, 0).single())
+        .map_or(window_start, |at| at.date_naive().max(window_start));
+
+    let (mut coding, mut commits, mut pomodoros, mut late_night) = (0u32, 0u32, 0u32, 0u32);
+    for day in std::iter::once(&data.today).chain(&data.history) {
+        let in_period = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d")
+            .is_ok_and(|date| date >= start && date <= today);
+        if in_period {
+            coding += day.coding_minutes;
+            commits += day.commits;
+            pomodoros += day.pomodoro_sessions;
+            late_night += day.late_night_minutes;
+        }
+    }
+    if coding == 0 {
+        return BranchScores::default();
+    }
+
+    let coding = coding as f32;
+    BranchScores {
+        committer: commits as f32 / (coding / 60.0) / COMMITS_PER_HOUR,
+        focused: pomodoros as f32 * MINUTES_PER_POMODORO / coding / FOCUS_RATIO,
+        night_owl: late_night as f32 / coding / NIGHT_RATIO,
+    }
+}
+
+/// 가장 높은 점수의 갈래 (모두 기준치 미만이면 Balanced, 동점이면 커밋 → 집중 → 밤 순)
+fn branch(scores: &BranchScores) -> EvolutionBranch {
+    [
+        (EvolutionBranch::Committer, scores.committer),
+        (EvolutionBranch::Focused, scores.focused),
+        (EvolutionBranch::NightOwl, scores.night_owl),
+    ]
+    .into_iter()
+    .filter(|(_, score)| *score >= 1.0)
+    .reduce(|best, next| if next.1 > best.1 { next } else { best })
+    .map_or(EvolutionBranch::Balanced, |(branch, _)| branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::activity::DailySummary;
+
+    fn noon(date: NaiveDate) -> DateTime<Local> {
+        Local.from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap()).unwrap()
+    }
+
+    fn date(days_ago: i64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 30).unwrap() - Duration::days(days_ago)
+    }
+
+    /// (며칠 전, 코딩 분, 커밋, 뽀모도로, 밤 코딩 분)
+    fn day(days_ago: i64, coding: u32, commits: u32, pomodoros: u32, late_night: u32) -> DailySummary {
+        DailySummary {
+            date: date(days_ago).format("%Y-%m-%d").to_string(),
+            coding_minutes: coding,
+            commits,
+            pomodoro_sessions: pomodoros,
+            late_night_minutes: late_night,
+            ..DailySummary::default()
+        }
+    }
+
+    fn scores_of(days: Vec<DailySummary>) -> BranchScores {
+        let data = AppData {
+            history: days,
+            ..AppData::default()
+        };
+        scores(&data, date(0))
+    }
+
+    #[test]
+    fn stage_boundaries() {
+        use LifeStage::*;
+        let cases = [
+            (0, Kitten),
+            (1, Kitten),
+            (4, Kitten),
+            (5, Young),
+            (14, Young),
+            (15, Adult),
+            (29, Adult),
+            (30, Elder),
+            (49, Elder),
+            (50, Legendary),
+            (u32::MAX, Legendary),
+        ];
+        for (level, stage) in cases {
+            assert_eq!(LifeStage::for_level(level), stage, "level {}", level);
+        }
+    }
+
+    #[test]
+    fn branch_picks_the_strongest_habit() {
+        use EvolutionBranch::*;
+        let scores = |committer, focused, night_owl| BranchScores {
+            committer,
+            focused,
+            night_owl,
+        };
+        let cases = [
+            (scores(0.0, 0.0, 0.0), Balanced),
+            (scores(0.99, 0.5, 0.99), Balanced),
+            (scores(1.0, 0.0, 0.0), Committer),
+            (scores(1.2, 1.5, 0.0), Focused),
+            (scores(1.2, 1.5, 3.0), NightOwl),
+            // 동점: 커밋 → 집중 → 밤
+            (scores(2.0, 2.0, 0.0), Committer),
+            (scores(0.0, 2.0, 2.0), Focused),
+            (scores(1.0, 1.0, 1.0), Committer),
+            (scores(1.0, 3.0, 3.0), Focused),
+        ];
+        for (scores, expected) in cases {
+            assert_eq!(branch(&scores), expected, "{:?}", scores);
+        }
+    }
+
+    #[test]
+    fn scores_come_from_the_recent_window() {
+        // 10시간 코딩, 커밋 20개 (시간당 2), 뽀모도로 4번 (100분 = 1/6), 밤 150분 (1/4)
+        let recent = scores_of(vec![day(0, 300, 10, 2, 0), day(3, 300, 10, 2, 150)]);
+        assert_eq!(recent.committer, 1.0);
+        assert!((recent.focused - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(recent.night_owl, 1.0);
+
+        // 30일보다 오래된 날은 빠짐
+        let old = scores_of(vec![day(0, 60, 0, 0, 0), day(BRANCH_WINDOW_DAYS, 600, 100, 0, 600)]);
+        assert_eq!(old, BranchScores::default());
+
+        // 코딩이 없으면 모두 0
+        assert_eq!(scores_of(vec![day(1, 0, 5, 0, 0)]), BranchScores::default());
+    }
+
+    #[test]
+    fn scores_start_after_the_last_evolution() {
+        let mut data = AppData {
+            history: vec![day(10, 600, 100, 0, 0), day(1, 600, 0, 0, 600)],
+            ..AppData::default()
+        };
+        data.cat.evolution.evolved_at = Some(noon(date(5)).timestamp());
+        let scores = scores(&data, date(0));
+        assert_eq!((scores.committer, scores.night_owl), (0.0, 4.0));
+    }
+
+    #[test]
+    fn evolve_once_per_stage() {
+        let mut data = AppData {
+            history: vec![day(1, 600, 0, 24, 0)],
+            ..AppData::default()
+        };
+        data.cat.level = 4;
+        assert!(evolve(&mut data, noon(date(0))).is_none());
+
+        data.cat.level = 5;
+        let changed = evolve(&mut data, noon(date(0))).expect("evolves at level 5");
+        assert_eq!(changed.previous, LifeStage::Kitten);
+        assert_eq!(changed.evolution.stage, LifeStage::Young);
+        assert_eq!(changed.evolution.branch, Some(EvolutionBranch::Focused));
+        assert_eq!(data.cat.evolution.evolved_at, Some(noon(date(0)).timestamp()));
+
+        data.cat.level = 14;
+        assert!(evolve(&mut data, noon(date(0))).is_none());
+
+        // 단계를 건너뛰면 바로 마지막 단계로
+        data.cat.level = 31;
+        assert_eq!(evolve(&mut data, noon(date(0))).unwrap().evolution.stage, LifeStage::Elder);
+    }
+}
@@ -1,6 +1,20 @@
-use tauri::{AppHandle, Emitter};
+use crate::events::event_names;
+use crate::models::growth::commit_exp;
+use crate::services::achievements;
+use crate::services::cat_state::CatStateHandle;
+use crate::services::cats;
+use crate::services::cosmetics;
+use crate::services::dnd;
+use crate::services::evolution;
+use crate::services::growth;
+use crate::services::needs::{self, NeedsActivity};
+use crate::services::settings::SettingsState;
+use crate::services::state_machine::StateEvent;
+use crate::services::storage;
+use chrono::Timelike;
+use tauri::{AppHandle, Emitter, Manager};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 /// Git HEAD 변경 감지 (폴링 방식 - MVP)
@@ -11,8 +25,13 @@ pub async fn start_watcher(app: AppHandle) {
     loop {
         interval.tick().await;
 
-        // TODO: storage에서 등록된 repo 목록 가져오기
-        let repos: Vec<PathBuf> = vec![];
+        // 등록된 저장소 (Git 연동이 꺼져 있으면 감시 중지)
+        let settings = app.state::<SettingsState>().current();
+        if !settings.git_integration {
+            last_heads.clear();
+            continue;
+        }
+        let repos: Vec<PathBuf> = settings.git_repos.iter().map(PathBuf::from).collect();
 
         for repo in repos {
             if let Some(current_head) = read_head(&repo) {
@@ -22,10 +41,12 @@ pub async fn start_watcher(app: AppHandle) {
                     .unwrap_or(false);
 
                 if changed {
-                    let _ = app.emit("git:new-commit", serde_json::json!({
+                    let _ = app.emit(event_names::GIT_NEW_COMMIT, serde_json::json!({
                         "repo": repo.to_string_lossy().to_string(),
                         "head": current_head,
                     }));
+                    app.state::<CatStateHandle>().send(StateEvent::CommitDetected);
+                    record_commit(&app, &repo);
                 }
 
                 last_heads.insert(repo, current_head);
@@ -34,8 +55,50 @@ pub async fn start_watcher(app: AppHandle) {
     }
 }
 
+/// 커밋 수 / EXP / 욕구(커밋 = 간식) / 업적 기록 (저장소 규칙으로 고른 고양이에게)
+fn record_commit(app: &AppHandle, repo: &Path) {
+    let result = storage::update(app, |data| {
+        storage::roll_over_day(data);
+        let now = chrono::Local::now();
+        let repo = repo.to_string_lossy().to_string();
+        data.today.commits += 1;
+        *data.today.repo_commits.entry(repo.clone()).or_default() += 1;
+        // 오늘 커밋 수 기준 감쇠 (하루 상한 이후 20%)
+        let exp = commit_exp(data.today.commits) - commit_exp(data.today.commits - 1);
+        data.today.exp_gained += exp;
+        let credited = cats::credited(data, Some(&repo), now.hour());
+        let (level_up, evolved, new_achievements, new_items) = cats::with_cat(data, &credited, |data| {
+            data.cat.total_commits += 1;
+            data.cat.last_commit_at = Some(now.timestamp());
+            let level_up = growth::add_exp(&mut data.cat, exp);
+            needs::apply(&mut data.cat.needs, NeedsActivity::Commit, now.timestamp());
+            let evolved = evolution::evolve(data, now);
+            let new_achievements = achievements::unlock_new(data, now);
+            let new_items = cosmetics::unlock_new(data, now.timestamp());
+            (level_up, evolved, new_achievements, new_items)
+        });
+        (exp, level_up, evolved, data.cat.needs.clone(), new_achievements, new_items)
+    });
+    match result {
+        Ok((exp, level_up, evolved, needs, new_achievements, new_items)) => {
+            let _ = app.emit(
+                event_names::CAT_EXP_GAINED,
+                serde_json::json!({ "amount": exp, "source": "commit" }),
+            );
+            if let Some(level) = level_up {
+                dnd::emit(app, event_names::CAT_LEVEL_UP, level);
+            }
+            evolution::emit_evolved(app, &evolved);
+            needs::emit_changed(app, &needs);
+            achievements::emit_unlocked(app, &new_achievements);
+            cosmetics::emit_unlocked(app, &new_items);
+        }
+        Err(e) => eprintln!("Failed to save commit: {}", e),
+    }
+}
+
 /// .git/HEAD에서 현재 커밋 해시 읽기
-fn read_head(repo_path: &PathBuf) -> Option<String> {
+fn read_head(repo_path: &Path) -> Option<String> {
     let head_path = repo_path.join(".git").join("HEAD");
     let content = std::fs::read_to_string(&head_path).ok()?;
 
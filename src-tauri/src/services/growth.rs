@@ -0,0 +1,85 @@
+use crate::models::activity::DailySummary;
+use crate::models::growth::{commit_exp, exp_for_level, ExpBreakdown, EXP_PER_POMODORO};
+use crate::models::settings::{AppData, CatPersistence};
+
+/// EXP 적립 + 레벨업 처리
+///
+/// 레벨이 올랐으면 새 레벨 반환
+pub fn add_exp(cat: &mut CatPersistence, amount: u32) -> Option<u32> {
+    cat.exp += amount;
+    cat.total_exp += amount;
+
+    let before = cat.level;
+    while cat.exp >= exp_for_level(cat.level) {
+        cat.exp -= exp_for_level(cat.level);
+        cat.level += 1;
+    }
+    (cat.level > before).then_some(cat.level)
+}
+
+/// 오늘 활동 기록 → 연속 활동일(streak) 갱신
+pub fn touch_streak(data: &mut AppData) {
+    let today = data.today.date.clone();
+    if data.cat.last_active_date.as_deref() == Some(today.as_str()) {
+        return;
+    }
+
+    let yesterday = chrono::NaiveDate::parse_from_str(&today, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.pred_opt())
+        .map(|date| date.format("%Y-%m-%d").to_string());
+
+    data.cat.streak_days = if data.cat.last_active_date.is_some() && data.cat.last_active_date == yesterday {
+        data.cat.streak_days + 1
+    } else {
+        1
+    };
+    data.cat.last_active_date = Some(today);
+}
+
+/// 오늘 EXP 출처별 내역 (커밋 / 뽀모도로는 횟수로 다시 계산하고 나머지는 코딩)
+pub fn exp_breakdown(today: &DailySummary) -> ExpBreakdown {
+    let commits = commit_exp(today.commits);
+    let pomodoro = today.pomodoro_sessions * EXP_PER_POMODORO;
+    ExpBreakdown {
+        coding: today.exp_gained.saturating_sub(commits + pomodoro),
+        commits,
+        pomodoro,
+        total: today.exp_gained,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::growth::{DAILY_COMMIT_CAP, EXP_PER_COMMIT};
+
+    #[test]
+    fn breakdown_splits_todays_exp_by_source() {
+        let today = DailySummary {
+            commits: 2,
+            pomodoro_sessions: 1,
+            exp_gained: 45 + 2 * EXP_PER_COMMIT + EXP_PER_POMODORO,
+            ..DailySummary::default()
+        };
+        let expected = ExpBreakdown {
+            coding: 45,
+            commits: 2 * EXP_PER_COMMIT,
+            pomodoro: EXP_PER_POMODORO,
+            total: today.exp_gained,
+        };
+        assert_eq!(exp_breakdown(&today), expected);
+    }
+
+    #[test]
+    fn commits_past_the_daily_cap_are_discounted() {
+        let today = DailySummary {
+            commits: DAILY_COMMIT_CAP + 5,
+            exp_gained: commit_exp(DAILY_COMMIT_CAP + 5),
+            ..DailySummary::default()
+        };
+        let breakdown = exp_breakdown(&today);
+        assert_eq!(breakdown.commits, DAILY_COMMIT_CAP * EXP_PER_COMMIT + 5 * EXP_PER_COMMIT / 5);
+        assert_eq!(breakdown.coding, 0);
+    }
+}
@@ -0,0 +1,357 @@
+//! IDE / 에디터 / 포커스 / 프로젝트 / 언어 감지 (OS별 구현)
+//!
+//! macOS / Windows / Linux만 지원한다. 그 밖의 Unix(FreeBSD 등)는 IDE를 찾지 못해
+//! 코딩 시간이 쌓이지 않는다 (/proc 레이아웃과 IDE 규칙의 OS 구분이 Linux 기준).
+//!
+//! 활동 모니터는 이 모듈을 `SystemSource`를 통해서만 사용한다.
+use crate::services::ide_rules::IdeRuleSet;
+use crate::services::language;
+
+/// 포커스된 창 감지 결과
+#[derive(Debug, Clone)]
+pub enum Focus {
+    /// OS/세션에서 알 수 없음 → IDE 프로세스 존재 여부로 대체
+    Unknown,
+    /// 포커스된 앱 (데스크톱 등 포커스 없음이면 None)
+    Window(Option<FocusedApp>),
+}
+
+/// 포커스된 앱
+#[derive(Debug, Clone)]
+pub struct FocusedApp {
+    /// 표시 이름 (IDE면 IDE 이름, 아니면 WM_CLASS)
+    pub name: String,
+    /// IDE / 에디터인 경우 IDE 이름
+    pub ide: Option<String>,
+    /// IDE / 에디터 프로세스 (터미널이면 그 안의 에디터)
+    pub pid: Option<u32>,
+    /// 창 제목
+    pub title: Option<String>,
+}
+
+/// 실행 중인 IDE
+#[derive(Debug, Clone)]
+pub struct RunningIde {
+    pub name: String,
+    /// 알 수 있는 OS에서만 (Linux)
+    pub pid: Option<u32>,
+}
+
+/// 포커스된 창 감지 (OS별 분기)
+#[allow(unused_variables)]
+pub fn detect_focused_app(rules: &IdeRuleSet) -> Focus {
+    #[cfg(target_os = "linux")]
+    { detect_focused_app_x11(rules) }
+
+    #[cfg(not(target_os = "linux"))]
+    { Focus::Unknown }
+}
+
+/// 실행 중인 IDE 전부 감지 (OS별 분기, 규칙은 ide-rules.json)
+///
+/// 같은 IDE의 여러 프로세스는 하나로 합친다.
+#[allow(unused_variables)]
+pub fn detect_running_ides(rules: &IdeRuleSet) -> Vec<RunningIde> {
+    #[cfg(target_os = "macos")]
+    let ides = detect_running_ides_macos(rules);
+
+    #[cfg(target_os = "windows")]
+    let ides = detect_running_ides_windows(rules);
+
+    #[cfg(target_os = "linux")]
+    let ides = detect_running_ides_linux(rules);
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    let ides: Vec<RunningIde> = Vec::new();
+
+    let mut unique: Vec<RunningIde> = Vec::with_capacity(ides.len());
+    for ide in ides {
+        if !unique.iter().any(|seen| seen.name == ide.name) {
+            unique.push(ide);
+        }
+    }
+    unique
+}
+
+/// 포커스된 창이 풀스크린인지 (OS별 분기, 알 수 없으면 false)
+pub fn detect_fullscreen() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        crate::services::x11::with_connection(|conn| Some(conn.active_window_fullscreen())).unwrap_or(false)
+    }
+
+    // TODO: macOS (CGWindowList 창 크기 vs 화면), Windows (포그라운드 창 크기 vs 모니터)
+    #[cfg(not(target_os = "linux"))]
+    {
+        false
+    }
+}
+
+/// 열린 모든 창의 제목 (OS별 분기, 알 수 없으면 빈 목록)
+///
+/// 화면 공유 표시줄처럼 포커스되지 않은 창을 찾을 때 쓴다.
+pub fn window_titles() -> Vec<String> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::services::x11::with_connection(|conn| Some(conn.window_titles())).unwrap_or_default()
+    }
+
+    // TODO: macOS (CGWindowListCopyWindowInfo), Windows (EnumWindows)
+    #[cfg(not(target_os = "linux"))]
+    {
+        Vec::new()
+    }
+}
+
+/// 실행 중인 모든 프로세스의 이름 (경로 / .exe 제외, OS별 분기)
+///
+/// IDE 규칙과 무관한 앱(화면 공유 등)을 찾을 때 쓴다.
+pub fn running_process_names() -> Vec<String> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::services::procfs::processes()
+            .iter()
+            .flat_map(|p| [p.name.clone(), p.comm.clone()])
+            .collect()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let Ok(output) = std::process::Command::new("ps").args(["-A", "-o", "comm="]).output() else {
+            return Vec::new();
+        };
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|path| path.trim().rsplit('/').next())
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let Ok(output) = std::process::Command::new("tasklist").args(["/FO", "CSV", "/NH"]).output() else {
+            return Vec::new();
+        };
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split(',').next())
+            .map(|image| {
+                let image = image.trim_matches('"');
+                image.strip_suffix(".exe").unwrap_or(image).to_string()
+            })
+            .collect()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        Vec::new()
+    }
+}
+
+// ═══════════════════════════════════════
+// macOS: 프로세스 전체 경로로 감지
+// ═══════════════════════════════════════
+#[cfg(target_os = "macos")]
+fn detect_running_ides_macos(rules: &IdeRuleSet) -> Vec<RunningIde> {
+    // ps -A -o args= 로 전체 커맨드라인을 가져옴
+    let Ok(output) = std::process::Command::new("ps")
+        .args(["-A", "-o", "args="])
+        .output()
+    else {
+        return Vec::new();
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // 앱 번들 경로 → IDE 이름
+    stdout
+        .lines()
+        .filter_map(|line| rules.match_command_line(line))
+        .map(|rule| RunningIde {
+            name: rule.name.clone(),
+            pid: None,
+        })
+        .collect()
+}
+
+// ═══════════════════════════════════════
+// Windows: tasklist로 감지
+// ═══════════════════════════════════════
+#[cfg(target_os = "windows")]
+fn detect_running_ides_windows(rules: &IdeRuleSet) -> Vec<RunningIde> {
+    let Ok(output) = std::process::Command::new("tasklist")
+        .args(["/FO", "CSV", "/NH"])
+        .output()
+    else {
+        return Vec::new();
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // "Code.exe","1234",... → 첫 번째 컬럼이 이미지 이름
+    stdout
+        .lines()
+        .filter_map(|line| line.split(',').next())
+        .filter_map(|image| rules.match_image_name(image.trim_matches('"')))
+        .map(|rule| RunningIde {
+            name: rule.name.clone(),
+            pid: None,
+        })
+        .collect()
+}
+
+// ═══════════════════════════════════════
+// Linux: /proc 기반 (실행 파일 이름 일치)
+// ═══════════════════════════════════════
+#[cfg(target_os = "linux")]
+fn detect_running_ides_linux(rules: &IdeRuleSet) -> Vec<RunningIde> {
+    use crate::models::ide::IdeCategory;
+
+    let mut processes = crate::services::procfs::processes();
+    // 부모(메인) 프로세스가 대표 PID가 되도록 PID 순 정렬
+    processes.sort_by_key(|p| p.pid);
+
+    // GUI IDE / 에디터 먼저, 그다음 터미널 에디터
+    let gui = processes.iter().filter_map(|p| {
+        let rule = rules.match_process(&p.name, &p.comm, &p.args)?;
+        matches!(rule.category, IdeCategory::Ide | IdeCategory::Editor).then(|| RunningIde {
+            name: rule.name.clone(),
+            pid: Some(p.pid),
+        })
+    });
+    let terminal = terminal_editors(rules, &processes)
+        .into_iter()
+        .map(|(pid, name)| RunningIde {
+            name,
+            pid: Some(pid),
+        });
+
+    gui.chain(terminal).collect()
+}
+
+// ═══════════════════════════════════════
+// Linux: 터미널 에디터 (vim, neovim, emacs -nw, helix, nano)
+// ═══════════════════════════════════════
+
+/// TTY 포그라운드에서 실행 중인 터미널 에디터 → (PID, 표시 이름)
+#[cfg(target_os = "linux")]
+fn terminal_editors(
+    rules: &IdeRuleSet,
+    processes: &[std::sync::Arc<crate::services::procfs::Process>],
+) -> Vec<(u32, String)> {
+    use crate::models::ide::IdeCategory;
+
+    processes
+        .iter()
+        .filter_map(|p| {
+            let rule = rules.match_process(&p.name, &p.comm, &p.args)?;
+            (rule.category == IdeCategory::TerminalEditor && p.is_tty_foreground())
+                .then(|| (p.pid, rule.name.clone()))
+        })
+        .collect()
+}
+
+/// 포커스된 터미널 에뮬레이터 안에서 돌고 있는 터미널 에디터
+///
+/// 에디터가 터미널의 자손이면 해당 터미널 안에 있는 것으로 본다.
+/// tmux / screen / zellij 안의 에디터는 터미널과 직접 연결되지 않으므로,
+/// 포커스된 터미널에 멀티플렉서 클라이언트가 붙어 있으면 멀티플렉서 아래 에디터도 인정한다.
+#[cfg(target_os = "linux")]
+fn focused_terminal_editor(rules: &IdeRuleSet, terminal_pid: u32) -> Option<(u32, String)> {
+    use crate::services::procfs::{self, Process};
+
+    let processes = procfs::processes();
+    let by_pid = procfs::by_pid(&processes);
+
+    let is_multiplexer = |p: &Process| {
+        [p.name.as_str(), p.comm.as_str()].iter().any(|name| {
+            let name = name.to_ascii_lowercase();
+            name.starts_with("tmux") || name.starts_with("screen") || name.starts_with("zellij")
+        })
+    };
+    let terminal_has_multiplexer = processes
+        .iter()
+        .any(|p| is_multiplexer(p) && procfs::is_descendant(&by_pid, p.pid, terminal_pid));
+
+    terminal_editors(rules, &processes)
+        .into_iter()
+        .find(|(pid, _)| {
+            procfs::is_descendant(&by_pid, *pid, terminal_pid)
+                || (terminal_has_multiplexer
+                    && procfs::ancestors(&by_pid, *pid).any(|p| is_multiplexer(p)))
+        })
+}
+
+// ═══════════════════════════════════════
+// Linux: X11 활성 창 (_NET_ACTIVE_WINDOW + WM_CLASS)
+// ═══════════════════════════════════════
+#[cfg(target_os = "linux")]
+fn detect_focused_app_x11(rules: &IdeRuleSet) -> Focus {
+    use crate::models::ide::IdeCategory;
+    use crate::services::{procfs, x11};
+
+    // X 연결 자체가 안 되면(Wayland 등) 알 수 없음으로 처리
+    let Some(window) = x11::with_connection(|conn| Some(conn.active_window())) else {
+        return Focus::Unknown;
+    };
+    let Some(window) = window else {
+        return Focus::Window(None);
+    };
+
+    // WM_CLASS (class / instance) → IDE 이름
+    let by_class = [window.class.as_deref(), window.instance.as_deref()]
+        .into_iter()
+        .flatten()
+        .find_map(|class| rules.match_window_class(class));
+
+    // WM_CLASS가 비어 있거나 낯선 경우 소유 프로세스(_NET_WM_PID)로 재확인
+    let rule = by_class.or_else(|| {
+        let pid = window.pid?;
+        let processes = procfs::processes();
+        let process = processes.iter().find(|p| p.pid == pid)?;
+        rules.match_process(&process.name, &process.comm, &process.args)
+    });
+
+    let (app_name, ide, pid) = match rule {
+        // 터미널 에뮬레이터: 그 안에서 에디터가 돌고 있을 때만 코딩
+        Some(rule) if rule.category == IdeCategory::Terminal => {
+            let editor = window
+                .pid
+                .and_then(|pid| focused_terminal_editor(rules, pid));
+            match editor {
+                Some((pid, name)) => (Some(rule.name.clone()), Some(name), Some(pid)),
+                None => (Some(rule.name.clone()), None, None),
+            }
+        }
+        Some(rule) => (Some(rule.name.clone()), Some(rule.name.clone()), window.pid),
+        None => (None, None, None),
+    };
+
+    let name = match (&app_name, &ide) {
+        (Some(terminal), Some(editor)) if terminal != editor => format!("{} ({})", editor, terminal),
+        _ => app_name
+            .or(window.class)
+            .or(window.instance)
+            .unwrap_or_else(|| format!("window 0x{:x}", window.window)),
+    };
+
+    Focus::Window(Some(FocusedApp {
+        name,
+        ide,
+        pid,
+        title: window.title,
+    }))
+}
+
+/// 작업 중인 언어: 창 제목의 파일 → 에디터 인자의 파일 → 저장소 주 언어
+#[allow(unused_variables)]
+pub fn detect_language(pid: Option<u32>, title: Option<&str>, project: Option<&str>) -> Option<String> {
+    let from_file = title.and_then(language::language_from_text);
+
+    #[cfg(target_os = "linux")]
+    let from_file = from_file.or_else(|| pid.and_then(language::language_from_process));
+
+    from_file
+        .map(str::to_string)
+        .or_else(|| project.and_then(language::dominant_language))
+}
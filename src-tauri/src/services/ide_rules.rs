@@ -0,0 +1,119 @@
+use crate::models::ide::{default_ide_rules, IdeRule};
+use regex::Regex;
+use std::sync::{Arc, RwLock};
+
+/// 정규식까지 컴파일된 규칙
+struct CompiledRule {
+    rule: IdeRule,
+    args: Option<Regex>,
+}
+
+/// 현재 OS에 적용되는 IDE 감지 규칙 모음
+pub struct IdeRuleSet {
+    rules: Vec<CompiledRule>,
+}
+
+impl IdeRuleSet {
+    /// 규칙 검증 + 컴파일 (잘못된 정규식이 있으면 에러)
+    pub fn compile(rules: Vec<IdeRule>) -> Result<Self, String> {
+        let mut compiled = Vec::with_capacity(rules.len());
+        for rule in rules {
+            if rule.executable.trim().is_empty() || rule.name.trim().is_empty() {
+                return Err("IDE rule requires both executable and name".to_string());
+            }
+            let args = match &rule.args_pattern {
+                Some(pattern) => Some(
+                    Regex::new(pattern)
+                        .map_err(|e| format!("Invalid args pattern for {}: {}", rule.name, e))?,
+                ),
+                None => None,
+            };
+            compiled.push(CompiledRule { rule, args });
+        }
+        Ok(Self { rules: compiled })
+    }
+
+    /// 기본 내장 규칙
+    pub fn builtin() -> Self {
+        Self::compile(default_ide_rules()).expect("built-in IDE rules are valid")
+    }
+
+    /// 저장용 원본 규칙 (모든 OS 포함)
+    pub fn rules(&self) -> Vec<IdeRule> {
+        self.rules.iter().map(|c| c.rule.clone()).collect()
+    }
+
+    fn active(&self) -> impl Iterator<Item = &CompiledRule> {
+        self.rules.iter().filter(|c| c.rule.applies_to_current_os())
+    }
+
+    /// Linux: 실행 파일 이름(basename 또는 comm) 일치 + 인자 정규식
+    pub fn match_process(&self, name: &str, comm: &str, args: &[String]) -> Option<&IdeRule> {
+        self.active()
+            .find(|c| {
+                (c.rule.executable == name || c.rule.executable == comm)
+                    && c.args.as_ref().is_none_or(|re| re.is_match(&args.join(" ")))
+            })
+            .map(|c| &c.rule)
+    }
+
+    /// macOS: `ps` 커맨드라인 한 줄에 앱 번들 이름 포함 + 인자 정규식
+    #[cfg(target_os = "macos")]
+    pub fn match_command_line(&self, line: &str) -> Option<&IdeRule> {
+        self.active()
+            .find(|c| {
+                line.contains(&c.rule.executable)
+                    && c.args.as_ref().is_none_or(|re| re.is_match(line))
+            })
+            .map(|c| &c.rule)
+    }
+
+    /// Windows: 이미지 이름 일치 (대소문자 무시)
+    #[cfg(target_os = "windows")]
+    pub fn match_image_name(&self, image: &str) -> Option<&IdeRule> {
+        self.active()
+            .find(|c| c.rule.executable.eq_ignore_ascii_case(image))
+            .map(|c| &c.rule)
+    }
+
+    /// X11: WM_CLASS 일치 (대소문자 무시)
+    pub fn match_window_class(&self, class: &str) -> Option<&IdeRule> {
+        self.active()
+            .find(|c| {
+                c.rule
+                    .window_class
+                    .as_deref()
+                    .is_some_and(|wc| wc.eq_ignore_ascii_case(class))
+            })
+            .map(|c| &c.rule)
+    }
+}
+
+/// 실행 중 공유되는 IDE 규칙
+///
+/// 규칙이 교체되면 활동 모니터가 다음 틱부터 새 규칙을 사용한다.
+pub struct IdeRulesState {
+    rules: RwLock<Arc<IdeRuleSet>>,
+}
+
+impl IdeRulesState {
+    pub fn new(rules: IdeRuleSet) -> Self {
+        Self {
+            rules: RwLock::new(Arc::new(rules)),
+        }
+    }
+
+    pub fn current(&self) -> Arc<IdeRuleSet> {
+        match self.rules.read() {
+            Ok(rules) => rules.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    pub fn replace(&self, rules: IdeRuleSet) {
+        match self.rules.write() {
+            Ok(mut current) => *current = Arc::new(rules),
+            Err(poisoned) => *poisoned.into_inner() = Arc::new(rules),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! 실제 사용자 유휴 시간 (키보드/마우스 기준)
+//!
+//! 키 입력 내용은 절대 읽지 않고, OS가 제공하는 "마지막 입력 이후 경과 시간"만 조회한다.
+
+/// 마지막 키보드/마우스 입력 이후 경과 시간 (초)
+///
+/// OS에서 알 수 없으면 None → 호출 측에서 IDE 감지 기반 유휴 시간으로 대체한다.
+pub async fn user_idle_seconds() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(ms) = super::x11::with_connection(|conn| conn.idle_millis()) {
+            return Some(ms / 1000);
+        }
+        logind_idle_seconds().await
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+// ═══════════════════════════════════════
+// Linux (Wayland 등 X11 불가): systemd-logind IdleHint
+// ═══════════════════════════════════════
+#[cfg(target_os = "linux")]
+async fn logind_idle_seconds() -> Option<u64> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tokio::sync::OnceCell;
+    use zbus::proxy::{Builder, CacheProperties};
+
+    static SESSION: OnceCell<Option<zbus::Proxy<'static>>> = OnceCell::const_new();
+
+    let session = SESSION
+        .get_or_init(|| async {
+            let conn = zbus::Connection::system().await.ok()?;
+            Builder::<zbus::Proxy>::new(&conn)
+                .destination("org.freedesktop.login1")
+                .ok()?
+                .path("/org/freedesktop/login1/session/auto")
+                .ok()?
+                .interface("org.freedesktop.login1.Session")
+                .ok()?
+                .cache_properties(CacheProperties::No)
+                .build()
+                .await
+                .ok()
+        })
+        .await
+        .as_ref()?;
+
+    let idle: bool = session.get_property("IdleHint").await.ok()?;
+    if !idle {
+        return Some(0);
+    }
+
+    // IdleSinceHint: 유휴 진입 시각 (CLOCK_REALTIME, 마이크로초)
+    let since_us: u64 = session.get_property("IdleSinceHint").await.ok()?;
+    let now_us = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_micros() as u64;
+    Some(now_us.saturating_sub(since_us) / 1_000_000)
+}
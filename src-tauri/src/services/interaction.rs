@@ -0,0 +1,248 @@
+//! 클릭 인터랙션 (클릭 / 더블클릭 / 쓰다듬기 / 드래그)
+//!
+//! 만질 때마다 애정도가 오르지만 같은 동작은 쿨다운 안에 다시 쳐주지 않고,
+//! 짧은 시간에 너무 자주 만지면 귀찮아한다. 쿨다운 / 귀찮음 중의 터치는 반응만 하고
+//! 고양이 상태는 바꾸지 않는다.
+use crate::events::event_names;
+use crate::models::cat::{CatReaction, ClickKind};
+use crate::services::cat_state::CatStateHandle;
+use crate::services::state_machine::StateEvent;
+use crate::services::storage;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 이 시간 안에 이만큼 넘게 만지면 귀찮아함
+const SPAM_WINDOW_SECS: u64 = 10;
+const SPAM_TOUCHES: usize = 6;
+
+/// (애니메이션, 이모지, 말풍선)
+type Reaction = (&'static str, &'static str, &'static str);
+
+const CLICK_REACTIONS: &[Reaction] = &[
+    ("meow", "😺", "meow!"),
+    ("chirp", "😸", "mrrp?"),
+    ("purr", "😽", "purr..."),
+    ("stretch", "😸", "*stretch*"),
+    ("cheer", "😺", "code with me~"),
+];
+const DOUBLE_CLICK_REACTIONS: &[Reaction] = &[
+    ("headbutt", "😻", "*headbutt*"),
+    ("roll", "😹", "nya nya~!"),
+    ("pounce", "😼", "gotcha!"),
+];
+const PET_REACTIONS: &[Reaction] = &[
+    ("purr", "😻", "purrrr~"),
+    ("knead", "🥰", "*kneads*"),
+    ("slowBlink", "😽", "*slow blink*"),
+    ("love", "😻", "😻 love it!"),
+    ("purr", "🥰", "more pets!"),
+];
+const SLEEPY_PET_REACTIONS: &[Reaction] = &[
+    ("dreamPurr", "😴", "purr... zzz"),
+    ("twitch", "😴", "*ear twitch*"),
+];
+const DRAG_REACTIONS: &[Reaction] = &[
+    ("wheee", "🙀", "wheee~!"),
+    ("dangle", "😿", "put me down!"),
+    ("flail", "🙀", "*flails*"),
+];
+const ANNOYED_REACTIONS: &[Reaction] = &[
+    ("hiss", "😾", "I'm busy!"),
+    ("ignore", "😾", "...meow."),
+    ("swat", "😾", "stahp!"),
+    ("grumble", "😾", "okay okay!"),
+];
+
+/// 동작별 애정도 / 쿨다운 (초)
+fn affection_for(kind: ClickKind) -> (u32, u64) {
+    match kind {
+        ClickKind::Single => (1, 2),
+        ClickKind::Double => (2, 3),
+        ClickKind::LongPress => (3, 10),
+        ClickKind::Drag => (0, 5), // 옮겨지는 건 별로 안 좋아함
+    }
+}
+
+/// 최근 만진 기록
+#[derive(Default)]
+pub struct InteractionState(Mutex<Interaction>);
+
+/// 터치 판정
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Touch {
+    /// 인정된 터치 (얻은 애정도, 드래그는 0)
+    Counted(u32),
+    /// 같은 동작의 쿨다운 중
+    Cooldown,
+    /// 너무 자주 만짐
+    Annoyed,
+}
+
+#[derive(Default)]
+struct Interaction {
+    /// 동작별 마지막으로 애정도를 준 시각
+    rewarded: HashMap<ClickKind, Instant>,
+    /// 귀찮음 판정용 최근 터치 시각
+    recent: VecDeque<Instant>,
+}
+
+impl Interaction {
+    /// 터치 기록 → 판정
+    fn touch(&mut self, kind: ClickKind, now: Instant) -> Touch {
+        let window = Duration::from_secs(SPAM_WINDOW_SECS);
+        while self
+            .recent
+            .front()
+            .is_some_and(|&at| now.duration_since(at) >= window)
+        {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(now);
+        if self.recent.len() > SPAM_TOUCHES {
+            return Touch::Annoyed;
+        }
+
+        let (affection, cooldown_secs) = affection_for(kind);
+        let ready = self
+            .rewarded
+            .get(&kind)
+            .is_none_or(|&at| now.duration_since(at).as_secs() >= cooldown_secs);
+        if !ready {
+            return Touch::Cooldown;
+        }
+        self.rewarded.insert(kind, now);
+        Touch::Counted(affection)
+    }
+}
+
+/// 고양이 만지기: 애정도 기록 → 상태 머신 → `cat:reaction`
+pub fn touch(app: &AppHandle, kind: ClickKind) -> Result<CatReaction, String> {
+    let touched = app
+        .state::<InteractionState>()
+        .0
+        .lock()
+        .map_err(|e| e.to_string())?
+        .touch(kind, Instant::now());
+    let gained = match touched {
+        Touch::Counted(gained) => gained,
+        Touch::Cooldown | Touch::Annoyed => 0,
+    };
+
+    let affection = if gained > 0 {
+        storage::update(app, |data| {
+            data.cat.affection += gained;
+            data.cat.affection
+        })?
+    } else {
+        storage::load(app)?.cat.affection
+    };
+
+    let cat_state = app.state::<CatStateHandle>();
+    let sleeping = cat_state.current().as_str() == "sleeping";
+    let annoyed = touched == Touch::Annoyed;
+    let (reaction, emoji, message) = pick(reactions(kind, annoyed, sleeping), fastrand::usize);
+
+    let reaction = CatReaction {
+        kind,
+        reaction: reaction.to_string(),
+        emoji: emoji.to_string(),
+        message: message.to_string(),
+        affection_gained: gained,
+        affection,
+        annoyed,
+    };
+    if let Touch::Counted(_) = touched {
+        cat_state.send(StateEvent::UserClicked(kind));
+    }
+    let _ = app.emit(event_names::CAT_REACTION, &reaction);
+    Ok(reaction)
+}
+
+/// 동작 / 기분에 맞는 반응 목록
+fn reactions(kind: ClickKind, annoyed: bool, sleeping: bool) -> &'static [Reaction] {
+    match kind {
+        _ if annoyed => ANNOYED_REACTIONS,
+        ClickKind::Single => CLICK_REACTIONS,
+        ClickKind::Double => DOUBLE_CLICK_REACTIONS,
+        ClickKind::LongPress if sleeping => SLEEPY_PET_REACTIONS,
+        ClickKind::LongPress => PET_REACTIONS,
+        ClickKind::Drag => DRAG_REACTIONS,
+    }
+}
+
+/// 반응 하나 고르기 (`choose`: 0..n 범위에서 하나, 실제로는 무작위)
+fn pick(reactions: &[Reaction], choose: impl FnOnce(std::ops::Range<usize>) -> usize) -> Reaction {
+    reactions[choose(0..reactions.len()) % reactions.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(start: Instant, secs: u64) -> Instant {
+        start + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn affection_per_kind() {
+        for (kind, expected) in [
+            (ClickKind::Single, Touch::Counted(1)),
+            (ClickKind::Double, Touch::Counted(2)),
+            (ClickKind::LongPress, Touch::Counted(3)),
+            (ClickKind::Drag, Touch::Counted(0)),
+        ] {
+            assert_eq!(Interaction::default().touch(kind, Instant::now()), expected, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn cooldown_is_per_kind() {
+        let start = Instant::now();
+        let mut interaction = Interaction::default();
+        // (초, 동작, 판정) — 클릭 쿨다운 2초, 쓰다듬기 10초
+        let touches = [
+            (0, ClickKind::Single, Touch::Counted(1)),
+            (1, ClickKind::Single, Touch::Cooldown),
+            (1, ClickKind::LongPress, Touch::Counted(3)),
+            (2, ClickKind::Single, Touch::Counted(1)),
+            (10, ClickKind::LongPress, Touch::Cooldown),
+            (11, ClickKind::LongPress, Touch::Counted(3)),
+        ];
+        for (at, kind, expected) in touches {
+            assert_eq!(interaction.touch(kind, secs(start, at)), expected, "{}s {:?}", at, kind);
+        }
+    }
+
+    #[test]
+    fn spam_annoys_until_the_window_passes() {
+        let start = Instant::now();
+        let mut interaction = Interaction::default();
+        let mut results = Vec::new();
+        for _ in 0..SPAM_TOUCHES + 2 {
+            results.push(interaction.touch(ClickKind::Single, start));
+        }
+        assert_eq!(results[0], Touch::Counted(1));
+        assert!(results[1..SPAM_TOUCHES].iter().all(|touch| *touch == Touch::Cooldown));
+        assert!(results[SPAM_TOUCHES..].iter().all(|touch| *touch == Touch::Annoyed));
+
+        // 귀찮아한 터치는 애정도를 주지 않고, 창이 지나면 다시 인정
+        assert_eq!(interaction.touch(ClickKind::Double, start), Touch::Annoyed);
+        let later = secs(start, SPAM_WINDOW_SECS);
+        assert_eq!(interaction.touch(ClickKind::Double, later), Touch::Counted(2));
+    }
+
+    #[test]
+    fn reactions_follow_kind_mood_and_choice() {
+        assert_eq!(reactions(ClickKind::LongPress, false, true), SLEEPY_PET_REACTIONS);
+        assert_eq!(reactions(ClickKind::LongPress, false, false), PET_REACTIONS);
+        assert_eq!(reactions(ClickKind::Drag, true, false), ANNOYED_REACTIONS);
+
+        assert_eq!(pick(DRAG_REACTIONS, |range| range.start), DRAG_REACTIONS[0]);
+        assert_eq!(pick(DRAG_REACTIONS, |range| range.end - 1), DRAG_REACTIONS[2]);
+        for (i, expected) in CLICK_REACTIONS.iter().enumerate() {
+            assert_eq!(pick(CLICK_REACTIONS, |_| i), *expected);
+        }
+    }
+}
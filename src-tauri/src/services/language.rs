@@ -0,0 +1,113 @@
+//! 작업 중인 언어 추정
+//!
+//! 포커스된 창 제목 / 에디터 인자의 파일 확장자를 우선 보고,
+//! 알 수 없으면 저장소에서 가장 많은 언어(git ls-files 기준)를 사용한다.
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// 저장소 주 언어 캐시 유효 시간
+const DOMINANT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// 확장자 → 언어 이름
+pub fn language_for_extension(ext: &str) -> Option<&'static str> {
+    let language = match ext.to_ascii_lowercase().as_str() {
+        "rs" => "Rust",
+        "ts" | "tsx" | "mts" | "cts" => "TypeScript",
+        "js" | "jsx" | "mjs" | "cjs" => "JavaScript",
+        "py" | "pyi" => "Python",
+        "go" => "Go",
+        "java" => "Java",
+        "kt" | "kts" => "Kotlin",
+        "scala" | "sc" => "Scala",
+        "swift" => "Swift",
+        "c" | "h" => "C",
+        "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => "C++",
+        "cs" => "C#",
+        "rb" => "Ruby",
+        "php" => "PHP",
+        "dart" => "Dart",
+        "lua" => "Lua",
+        "zig" => "Zig",
+        "ex" | "exs" => "Elixir",
+        "hs" => "Haskell",
+        "ml" | "mli" => "OCaml",
+        "sh" | "bash" | "zsh" | "fish" => "Shell",
+        "sql" => "SQL",
+        "html" | "htm" => "HTML",
+        "css" | "scss" | "sass" | "less" => "CSS",
+        "vue" => "Vue",
+        "svelte" => "Svelte",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// 텍스트(창 제목, 인자) 안의 파일 이름에서 언어 추정
+pub fn language_from_text(text: &str) -> Option<&'static str> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, '[' | ']' | '(' | ')' | '"' | '\''))
+        .filter_map(|token| {
+            let file = token.rsplit('/').next()?;
+            let (stem, ext) = file.rsplit_once('.')?;
+            (!stem.is_empty()).then_some(ext)
+        })
+        .find_map(language_for_extension)
+}
+
+/// 에디터 실행 인자의 파일 이름에서 언어 추정 (nvim src/main.rs 등)
+#[cfg(target_os = "linux")]
+pub fn language_from_process(pid: u32) -> Option<&'static str> {
+    let cmdline = std::fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    cmdline
+        .split(|b| *b == 0)
+        .skip(1)
+        .filter(|arg| !arg.is_empty() && arg[0] != b'-')
+        .find_map(|arg| language_from_text(&String::from_utf8_lossy(arg)))
+}
+
+/// 저장소에서 파일 수가 가장 많은 언어 (1시간 캐시)
+pub fn dominant_language(repo: &str) -> Option<String> {
+    type Cache = Mutex<HashMap<String, (Instant, Option<String>)>>;
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+
+    if let Some((at, language)) = cache.lock().ok()?.get(repo) {
+        if at.elapsed() < DOMINANT_CACHE_TTL {
+            return language.clone();
+        }
+    }
+
+    let language = scan_dominant_language(Path::new(repo));
+    cache
+        .lock()
+        .ok()?
+        .insert(repo.to_string(), (Instant::now(), language.clone()));
+    language
+}
+
+fn scan_dominant_language(repo: &Path) -> Option<String> {
+    // .gitignore를 존중하도록 추적 중인 파일만 센다
+    let output = std::process::Command::new("git")
+        .current_dir(repo)
+        .args(["ls-files", "-z"])
+        .output()
+        .ok()?;
+
+    let mut counts: HashMap<&'static str, usize> = HashMap::new();
+    for file in output.stdout.split(|b| *b == 0) {
+        let file = String::from_utf8_lossy(file);
+        let language = Path::new(file.as_ref())
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(language_for_extension);
+        if let Some(language) = language {
+            *counts.entry(language).or_default() += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(language, _)| language.to_string())
+}
@@ -1,4 +1,28 @@
 pub mod storage;
+pub mod settings;
 pub mod activity;
+pub mod ide_rules;
+pub mod project;
+pub mod language;
+pub mod session;
+pub mod growth;
+pub mod idle;
+pub mod ide_detect;
 pub mod git;
 pub mod state_machine;
+pub mod cat_state;
+pub mod pomodoro;
+pub mod needs;
+pub mod mood;
+pub mod interaction;
+pub mod achievements;
+pub mod cosmetics;
+pub mod evolution;
+pub mod cats;
+pub mod wellbeing;
+pub mod dnd;
+pub mod build_signal;
+#[cfg(target_os = "linux")]
+pub mod procfs;
+#[cfg(target_os = "linux")]
+pub mod x11;
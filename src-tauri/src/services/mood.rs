@@ -0,0 +1,344 @@
+//! 고양이 표정 계산
+//!
+//! 상태 표정(행동 정의) 위에 욕구 게이지와 최근 7일 활동 기록을 겹쳐 최종 표정을 정한다.
+//! 표정을 정한 근거는 `MoodFactor` 목록으로 함께 돌려준다.
+use crate::models::cat::{CatMood, CatNeeds, MoodFactor, MoodSignal};
+use crate::models::settings::AppData;
+use crate::services::needs::{self, LOW_NEED};
+use chrono::NaiveDate;
+
+/// 기록을 모으는 기간 (오늘 포함, 일)
+pub const WINDOW_DAYS: i64 = 7;
+
+/// 뿌듯: 활동한 날 하루 평균 커밋 수 / 연속 활동일 / 뽀모도로 완료 비율
+pub const PROUD_COMMITS_PER_DAY: f32 = 3.0;
+pub const PROUD_STREAK_DAYS: u32 = 7;
+pub const PROUD_POMODORO_COMPLETION: f32 = 0.8;
+/// 뽀모도로 완료 비율을 믿을 최소 횟수
+pub const PROUD_MIN_POMODOROS: u32 = 4;
+
+/// 걱정: 밤 코딩 비율 / 빌드 실패 비율
+pub const WORRIED_LATE_NIGHT_RATIO: f32 = 0.3;
+pub const WORRIED_BUILD_FAILURE_RATIO: f32 = 0.5;
+/// 비율을 믿을 최소 코딩 시간 (분) / 빌드 횟수
+pub const WORRIED_MIN_CODING_MINUTES: u32 = 60;
+pub const WORRIED_MIN_BUILDS: u32 = 3;
+
+/// 심심: 마지막 커밋 이후 시간
+pub const BORED_HOURS_SINCE_COMMIT: f32 = 48.0;
+
+/// 최근 7일 활동 합계
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MoodWindow {
+    /// 코딩이나 커밋이 있었던 날 수
+    pub active_days: u32,
+    pub commits: u32,
+    pub coding_minutes: u32,
+    pub late_night_minutes: u32,
+    pub builds: u32,
+    pub build_failures: u32,
+    pub pomodoros: u32,
+    pub pomodoro_cancelled: u32,
+    pub hours_since_commit: Option<f32>,
+    /// 어제 이후 활동이 없으면 끊긴 것으로 보고 0
+    pub streak_days: u32,
+}
+
+impl MoodWindow {
+    /// 오늘 요약 + 기록에서 최근 7일만 합산
+    pub fn collect(data: &AppData, today: NaiveDate, now: i64) -> Self {
+        let mut window = Self {
+            hours_since_commit: data
+                .cat
+                .last_commit_at
+                .map(|at| (now - at).max(0) as f32 / 3600.0),
+            ..Self::default()
+        };
+
+        let in_window = |date: &str| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map(|date| (0..WINDOW_DAYS).contains(&(today - date).num_days()))
+                .unwrap_or(false)
+        };
+        for day in std::iter::once(&data.today).chain(&data.history) {
+            if !in_window(&day.date) {
+                continue;
+            }
+            if day.coding_minutes > 0 || day.commits > 0 {
+                window.active_days += 1;
+            }
+            window.commits += day.commits;
+            window.coding_minutes += day.coding_minutes;
+            window.late_night_minutes += day.late_night_minutes;
+            window.builds += day.builds;
+            window.build_failures += day.build_failures;
+            window.pomodoros += day.pomodoro_sessions;
+            window.pomodoro_cancelled += day.pomodoro_cancelled;
+        }
+
+        let last_active = data
+            .cat
+            .last_active_date
+            .as_deref()
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+        if last_active.is_some_and(|date| (today - date).num_days() <= 1) {
+            window.streak_days = data.cat.streak_days;
+        }
+        window
+    }
+}
+
+/// 최종 표정 + 근거
+///
+/// 1. 잠들었거나 신난 순간, 부족한 욕구는 그대로 드러난다 (`needs::mood`)
+/// 2. 걱정은 평소(Happy) / 집중(Focused) 표정을 덮는다
+/// 3. 뿌듯함, 심심함은 평소 표정만 덮는다
+pub fn derive(state_mood: CatMood, needs: &CatNeeds, window: &MoodWindow) -> (CatMood, Vec<MoodFactor>) {
+    let cadence = ratio(window.commits, window.active_days);
+    let late_night = ratio(window.late_night_minutes, window.coding_minutes);
+    let build_failures = ratio(window.build_failures, window.builds);
+    let pomodoros = window.pomodoros + window.pomodoro_cancelled;
+    let completion = ratio(window.pomodoros, pomodoros);
+
+    let mut factors = vec![
+        factor(
+            MoodSignal::CommitCadence,
+            cadence,
+            cadence >= PROUD_COMMITS_PER_DAY,
+            CatMood::Proud,
+        ),
+        factor(
+            MoodSignal::Streak,
+            window.streak_days as f32,
+            window.streak_days >= PROUD_STREAK_DAYS,
+            CatMood::Proud,
+        ),
+        factor(
+            MoodSignal::PomodoroCompletion,
+            completion,
+            pomodoros >= PROUD_MIN_POMODOROS && completion >= PROUD_POMODORO_COMPLETION,
+            CatMood::Proud,
+        ),
+        factor(
+            MoodSignal::LateNight,
+            late_night,
+            window.coding_minutes >= WORRIED_MIN_CODING_MINUTES && late_night >= WORRIED_LATE_NIGHT_RATIO,
+            CatMood::Worried,
+        ),
+        factor(
+            MoodSignal::BuildFailures,
+            build_failures,
+            window.builds >= WORRIED_MIN_BUILDS && build_failures >= WORRIED_BUILD_FAILURE_RATIO,
+            CatMood::Worried,
+        ),
+    ];
+    if let Some(hours) = window.hours_since_commit {
+        factors.push(factor(
+            MoodSignal::HoursSinceCommit,
+            hours,
+            hours >= BORED_HOURS_SINCE_COMMIT,
+            CatMood::Bored,
+        ));
+    }
+    factors.extend([
+        factor(MoodSignal::Hunger, needs.hunger, needs.hunger < LOW_NEED, CatMood::Sad),
+        factor(MoodSignal::Energy, needs.energy, needs.energy < LOW_NEED, CatMood::Sleeping),
+        factor(MoodSignal::Happiness, needs.happiness, needs.happiness < LOW_NEED, CatMood::Sad),
+    ]);
+
+    let pushed = |mood: CatMood| factors.iter().any(|f| f.pushes.as_ref() == Some(&mood));
+    let needs_mood = needs::mood(state_mood.clone(), needs);
+    let mood = if needs_mood != state_mood || matches!(state_mood, CatMood::Sleeping | CatMood::Excited) {
+        needs_mood
+    } else if matches!(state_mood, CatMood::Happy | CatMood::Focused) && pushed(CatMood::Worried) {
+        CatMood::Worried
+    } else if state_mood == CatMood::Happy && pushed(CatMood::Proud) {
+        CatMood::Proud
+    } else if state_mood == CatMood::Happy && pushed(CatMood::Bored) {
+        CatMood::Bored
+    } else {
+        state_mood
+    };
+    (mood, factors)
+}
+
+fn factor(signal: MoodSignal, value: f32, active: bool, mood: CatMood) -> MoodFactor {
+    MoodFactor {
+        signal,
+        value,
+        pushes: active.then_some(mood),
+    }
+}
+
+/// 0으로 나누면 0
+fn ratio(part: u32, whole: u32) -> f32 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f32 / whole as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::activity::DailySummary;
+
+    const NOW: i64 = 1_000_000;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 10).unwrap()
+    }
+
+    fn day(days_ago: i64, commits: u32, coding_minutes: u32) -> DailySummary {
+        DailySummary {
+            date: (today() - chrono::Duration::days(days_ago)).format("%Y-%m-%d").to_string(),
+            commits,
+            coding_minutes,
+            ..DailySummary::default()
+        }
+    }
+
+    fn data(today_summary: DailySummary, history: Vec<DailySummary>) -> AppData {
+        AppData {
+            today: today_summary,
+            history,
+            ..AppData::default()
+        }
+    }
+
+    #[test]
+    fn window_sums_the_last_seven_days_only() {
+        let data = data(
+            day(0, 2, 30),
+            vec![
+                day(1, 1, 0),
+                // 빠진 날 (2~4일 전) 은 그냥 건너뜀
+                day(5, 0, 45),
+                day(6, 4, 60),
+                // 7일 전부터는 창 밖
+                day(7, 10, 600),
+                day(30, 10, 600),
+                // 날짜가 깨진 기록은 무시
+                DailySummary {
+                    date: "yesterday".to_string(),
+                    commits: 99,
+                    ..DailySummary::default()
+                },
+            ],
+        );
+        let window = MoodWindow::collect(&data, today(), NOW);
+        assert_eq!(window.active_days, 4);
+        assert_eq!(window.commits, 7);
+        assert_eq!(window.coding_minutes, 135);
+        assert_eq!(window.hours_since_commit, None);
+    }
+
+    #[test]
+    fn window_skips_idle_days_and_future_dates() {
+        let mut idle = day(2, 0, 0);
+        idle.builds = 3;
+        idle.build_failures = 2;
+        let data = data(day(0, 0, 0), vec![day(-1, 5, 50), idle]);
+        let window = MoodWindow::collect(&data, today(), NOW);
+        assert_eq!(window.active_days, 0);
+        assert_eq!(window.commits, 0);
+        assert_eq!((window.builds, window.build_failures), (3, 2));
+    }
+
+    #[test]
+    fn streak_counts_only_if_active_since_yesterday() {
+        for (last_active, expected) in [(Some(0), 9), (Some(1), 9), (Some(2), 0), (None, 0)] {
+            let mut data = data(day(0, 0, 0), vec![]);
+            data.cat.streak_days = 9;
+            data.cat.last_active_date = last_active.map(|days_ago| day(days_ago, 0, 0).date);
+            data.cat.last_commit_at = Some(NOW - 3 * 3600);
+            let window = MoodWindow::collect(&data, today(), NOW);
+            assert_eq!(window.streak_days, expected, "{:?}", last_active);
+            assert_eq!(window.hours_since_commit, Some(3.0));
+        }
+    }
+
+    #[test]
+    fn each_signal_picks_its_mood() {
+        use CatMood::*;
+        let calm = MoodWindow {
+            active_days: 5,
+            commits: 5,
+            coding_minutes: 600,
+            hours_since_commit: Some(2.0),
+            ..MoodWindow::default()
+        };
+        let cases: Vec<(&str, MoodWindow, CatMood, CatMood)> = vec![
+            ("calm", calm.clone(), Happy, Happy),
+            ("commit cadence", MoodWindow { commits: 15, ..calm.clone() }, Happy, Proud),
+            ("streak", MoodWindow { streak_days: PROUD_STREAK_DAYS, ..calm.clone() }, Happy, Proud),
+            ("pomodoros", MoodWindow { pomodoros: 4, ..calm.clone() }, Happy, Proud),
+            (
+                "pomodoros mostly cancelled",
+                MoodWindow { pomodoros: 3, pomodoro_cancelled: 3, ..calm.clone() },
+                Happy,
+                Happy,
+            ),
+            ("too few pomodoros", MoodWindow { pomodoros: 3, ..calm.clone() }, Happy, Happy),
+            ("late nights", MoodWindow { late_night_minutes: 180, ..calm.clone() }, Happy, Worried),
+            (
+                "late nights while focused",
+                MoodWindow { late_night_minutes: 180, ..calm.clone() },
+                Focused,
+                Worried,
+            ),
+            (
+                "late-night ratio needs enough coding",
+                MoodWindow { coding_minutes: 50, late_night_minutes: 50, ..calm.clone() },
+                Happy,
+                Happy,
+            ),
+            ("failing builds", MoodWindow { builds: 4, build_failures: 2, ..calm.clone() }, Happy, Worried),
+            ("too few builds", MoodWindow { builds: 2, build_failures: 2, ..calm.clone() }, Happy, Happy),
+            ("no commits lately", MoodWindow { hours_since_commit: Some(48.0), ..calm.clone() }, Happy, Bored),
+            ("never committed", MoodWindow { hours_since_commit: None, ..calm.clone() }, Happy, Happy),
+            // 걱정 > 뿌듯 > 심심
+            (
+                "worried beats proud",
+                MoodWindow { commits: 15, builds: 4, build_failures: 4, ..calm.clone() },
+                Happy,
+                Worried,
+            ),
+            (
+                "proud beats bored",
+                MoodWindow { streak_days: 7, hours_since_commit: Some(72.0), ..calm.clone() },
+                Happy,
+                Proud,
+            ),
+            // 뿌듯 / 심심은 평소 표정만 덮는다
+            ("focused stays focused", MoodWindow { commits: 15, ..calm.clone() }, Focused, Focused),
+            ("excited stays excited", MoodWindow { builds: 4, build_failures: 4, ..calm.clone() }, Excited, Excited),
+        ];
+        let needs = CatNeeds::default();
+        for (name, window, state_mood, expected) in cases {
+            assert_eq!(derive(state_mood, &needs, &window).0, expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn low_needs_beat_the_window() {
+        let worried = MoodWindow {
+            builds: 4,
+            build_failures: 4,
+            ..MoodWindow::default()
+        };
+        let hungry = CatNeeds {
+            hunger: 10.0,
+            ..CatNeeds::default()
+        };
+        let (mood, factors) = derive(CatMood::Happy, &hungry, &worried);
+        assert_eq!(mood, CatMood::Sad);
+        assert!(factors
+            .iter()
+            .any(|f| f.signal == MoodSignal::Hunger && f.pushes == Some(CatMood::Sad)));
+        assert!(factors
+            .iter()
+            .any(|f| f.signal == MoodSignal::BuildFailures && f.pushes == Some(CatMood::Worried)));
+    }
+}
@@ -0,0 +1,232 @@
+//! 고양이 욕구 (배부름 / 기력 / 행복)
+//!
+//! 시간 감소는 따로 타이머를 돌리지 않고, 욕구를 바꾸거나 읽을 때
+//! 마지막 갱신 이후 흐른 실제 시간만큼 한꺼번에 반영한다.
+use crate::events::event_names;
+use crate::models::cat::{CatMood, CatNeeds};
+use tauri::{AppHandle, Emitter};
+
+/// 시간당 감소량 (100 → 0: 배부름 25시간, 행복 50시간)
+pub const HUNGER_DECAY_PER_HOUR: f32 = 4.0;
+pub const HAPPINESS_DECAY_PER_HOUR: f32 = 2.0;
+
+/// 앱이 이보다 오래 꺼져 있었으면 그동안 쉰 것으로 보고 기력 회복 (초)
+pub const AWAY_REST_SECS: i64 = 30 * 60;
+
+/// 코딩 1분: 기력 소모 (4시간 연속 → -60), 행복 증가
+pub const ENERGY_PER_CODING_MINUTE: f32 = 0.25;
+pub const HAPPINESS_PER_CODING_MINUTE: f32 = 0.1;
+/// 휴식 1분: 기력 회복
+pub const ENERGY_PER_BREAK_MINUTE: f32 = 0.5;
+/// 커밋 = 간식
+pub const HUNGER_PER_COMMIT: f32 = 10.0;
+pub const HAPPINESS_PER_COMMIT: f32 = 3.0;
+/// 뽀모도로 완료 = 식사
+pub const HUNGER_PER_POMODORO: f32 = 15.0;
+pub const HAPPINESS_PER_POMODORO: f32 = 8.0;
+
+/// 이 값보다 낮으면 부족 → 표정에 드러남
+pub const LOW_NEED: f32 = 25.0;
+
+/// 휴식 시간은 이만큼 모였을 때 저장 (유휴 중 매분 저장하지 않도록)
+pub const BREAK_FLUSH_MINUTES: u32 = 5;
+
+/// 욕구를 채우는 활동
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NeedsActivity {
+    /// 코딩한 분
+    Coding(u32),
+    /// 코딩하지 않고 쉰 분
+    Break(u32),
+    Commit,
+    Pomodoro,
+}
+
+/// 마지막 갱신 이후 흐른 시간만큼 감소 반영 (처음이면 기준 시각만 기록)
+pub fn decay(needs: &mut CatNeeds, now: i64) {
+    if let Some(last) = needs.updated_at {
+        let elapsed = (now - last).max(0);
+        let hours = elapsed as f32 / 3600.0;
+        needs.hunger -= HUNGER_DECAY_PER_HOUR * hours;
+        needs.happiness -= HAPPINESS_DECAY_PER_HOUR * hours;
+        if elapsed >= AWAY_REST_SECS {
+            needs.energy += ENERGY_PER_BREAK_MINUTE * (elapsed / 60) as f32;
+        }
+        clamp(needs);
+    }
+    needs.updated_at = Some(now);
+}
+
+/// 감소 반영 후 활동만큼 채우기
+pub fn apply(needs: &mut CatNeeds, activity: NeedsActivity, now: i64) {
+    decay(needs, now);
+    match activity {
+        NeedsActivity::Coding(minutes) => {
+            needs.energy -= ENERGY_PER_CODING_MINUTE * minutes as f32;
+            needs.happiness += HAPPINESS_PER_CODING_MINUTE * minutes as f32;
+        }
+        NeedsActivity::Break(minutes) => {
+            needs.energy += ENERGY_PER_BREAK_MINUTE * minutes as f32;
+        }
+        NeedsActivity::Commit => {
+            needs.hunger += HUNGER_PER_COMMIT;
+            needs.happiness += HAPPINESS_PER_COMMIT;
+        }
+        NeedsActivity::Pomodoro => {
+            needs.hunger += HUNGER_PER_POMODORO;
+            needs.happiness += HAPPINESS_PER_POMODORO;
+        }
+    }
+    clamp(needs);
+}
+
+/// 현재 시각 기준 욕구 (저장하지 않는 조회용)
+pub fn current(needs: &CatNeeds, now: i64) -> CatNeeds {
+    let mut needs = needs.clone();
+    decay(&mut needs, now);
+    needs
+}
+
+/// 상태 표정에 욕구를 반영한 최종 표정
+///
+/// 잠들었거나 신난 순간은 그대로 두고, 기력이 바닥이면 졸린 얼굴,
+/// 배고프거나 외로우면 시무룩한 얼굴이 된다.
+pub fn mood(state_mood: CatMood, needs: &CatNeeds) -> CatMood {
+    match state_mood {
+        CatMood::Sleeping | CatMood::Excited => state_mood,
+        _ if needs.energy < LOW_NEED => CatMood::Sleeping,
+        _ if needs.hunger < LOW_NEED || needs.happiness < LOW_NEED => CatMood::Sad,
+        mood => mood,
+    }
+}
+
+/// 저장 후 `cat:needs-changed` 발생
+pub fn emit_changed(app: &AppHandle, needs: &CatNeeds) {
+    let _ = app.emit(event_names::CAT_NEEDS_CHANGED, needs);
+}
+
+fn clamp(needs: &mut CatNeeds) {
+    needs.hunger = needs.hunger.clamp(0.0, 100.0);
+    needs.energy = needs.energy.clamp(0.0, 100.0);
+    needs.happiness = needs.happiness.clamp(0.0, 100.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3600;
+
+    fn needs(hunger: f32, energy: f32, happiness: f32) -> CatNeeds {
+        CatNeeds {
+            hunger,
+            energy,
+            happiness,
+            updated_at: Some(0),
+        }
+    }
+
+    fn values(needs: &CatNeeds) -> (f32, f32, f32) {
+        (needs.hunger, needs.energy, needs.happiness)
+    }
+
+    #[test]
+    fn decay_follows_elapsed_time() {
+        // (경과 초, 결과 (배부름, 기력, 행복))
+        let cases = [
+            (0, (80.0, 80.0, 80.0)),
+            (10 * 60, (80.0 - 4.0 / 6.0, 80.0, 80.0 - 2.0 / 6.0)),
+            // 30분 이상 자리를 비우면 그동안 쉰 만큼 기력 회복
+            (AWAY_REST_SECS, (78.0, 95.0, 79.0)),
+            (HOUR, (76.0, 100.0, 78.0)),
+            (10 * HOUR, (40.0, 100.0, 60.0)),
+            (30 * HOUR, (0.0, 100.0, 20.0)),
+            // 시계가 거꾸로 가면 변화 없음
+            (-HOUR, (80.0, 80.0, 80.0)),
+        ];
+        for (elapsed, expected) in cases {
+            let mut current = needs(80.0, 80.0, 80.0);
+            decay(&mut current, elapsed);
+            let (hunger, energy, happiness) = values(&current);
+            assert!(
+                (hunger - expected.0).abs() < 0.01
+                    && (energy - expected.1).abs() < 0.01
+                    && (happiness - expected.2).abs() < 0.01,
+                "{}s: {:?} != {:?}",
+                elapsed,
+                values(&current),
+                expected
+            );
+            assert_eq!(current.updated_at, Some(elapsed));
+        }
+    }
+
+    #[test]
+    fn first_decay_only_records_the_time() {
+        let mut first = CatNeeds::default();
+        decay(&mut first, 1_000 * HOUR);
+        assert_eq!(values(&first), values(&CatNeeds::default()));
+        assert_eq!(first.updated_at, Some(1_000 * HOUR));
+    }
+
+    #[test]
+    fn apply_clamps_between_0_and_100() {
+        // (시작, 활동, 결과)
+        let cases = [
+            ((95.0, 50.0, 99.0), NeedsActivity::Commit, (100.0, 50.0, 100.0)),
+            ((90.0, 50.0, 95.0), NeedsActivity::Pomodoro, (100.0, 50.0, 100.0)),
+            ((50.0, 10.0, 50.0), NeedsActivity::Coding(60), (50.0, 0.0, 56.0)),
+            ((50.0, 90.0, 50.0), NeedsActivity::Break(60), (50.0, 100.0, 50.0)),
+            ((0.0, 0.0, 99.5), NeedsActivity::Coding(10), (0.0, 0.0, 100.0)),
+            ((10.0, 60.0, 10.0), NeedsActivity::Commit, (20.0, 60.0, 13.0)),
+        ];
+        for (start, activity, expected) in cases {
+            let mut current = needs(start.0, start.1, start.2);
+            apply(&mut current, activity, 0);
+            let actual = values(&current);
+            assert!(
+                (actual.0 - expected.0).abs() < 0.01
+                    && (actual.1 - expected.1).abs() < 0.01
+                    && (actual.2 - expected.2).abs() < 0.01,
+                "{:?} + {:?}: {:?} != {:?}",
+                start,
+                activity,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn current_does_not_change_the_saved_needs() {
+        let saved = needs(80.0, 80.0, 80.0);
+        let now = current(&saved, 10 * HOUR);
+        assert_eq!(values(&now), (40.0, 100.0, 60.0));
+        assert_eq!(saved, needs(80.0, 80.0, 80.0));
+    }
+
+    #[test]
+    fn mood_thresholds() {
+        use CatMood::*;
+        let full = (80.0, 80.0, 80.0);
+        // (상태 표정, 욕구, 결과)
+        let cases = [
+            (Happy, full, Happy),
+            (Focused, full, Focused),
+            (Happy, (80.0, LOW_NEED, 80.0), Happy),
+            (Happy, (80.0, LOW_NEED - 0.1, 80.0), Sleeping),
+            (Happy, (LOW_NEED, 80.0, LOW_NEED), Happy),
+            (Happy, (LOW_NEED - 0.1, 80.0, 80.0), Sad),
+            (Focused, (80.0, 80.0, LOW_NEED - 0.1), Sad),
+            // 기력이 바닥이면 배고파도 졸린 얼굴이 먼저
+            (Happy, (0.0, 0.0, 0.0), Sleeping),
+            // 잠들었거나 신난 순간은 그대로
+            (Excited, (0.0, 0.0, 0.0), Excited),
+            (Sleeping, (0.0, 80.0, 0.0), Sleeping),
+        ];
+        for (state_mood, (hunger, energy, happiness), expected) in cases {
+            let current = needs(hunger, energy, happiness);
+            assert_eq!(mood(state_mood.clone(), &current), expected, "{:?} {:?}", state_mood, values(&current));
+        }
+    }
+}
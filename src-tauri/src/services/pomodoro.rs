@@ -0,0 +1,230 @@
+use crate::events::event_names;
+use crate::models::activity::PomodoroStatus;
+use crate::models::achievement::Achievement;
+use crate::models::behavior::PomodoroPhase;
+use crate::models::cat::CatNeeds;
+use crate::models::cosmetic::CosmeticItem;
+use crate::models::evolution::EvolutionChanged;
+use crate::models::growth::EXP_PER_POMODORO;
+use crate::services::cat_state::CatStateHandle;
+use crate::services::achievements;
+use crate::services::cats;
+use crate::services::cosmetics;
+use crate::services::dnd;
+use crate::services::evolution;
+use crate::services::growth;
+use crate::services::needs::{self, NeedsActivity};
+use crate::services::state_machine::StateEvent;
+use crate::services::storage;
+use chrono::Timelike;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 진행 중인 뽀모도로 타이머
+#[derive(Default)]
+pub struct PomodoroState(Mutex<PomodoroTimer>);
+
+#[derive(Default)]
+struct PomodoroTimer {
+    run: Option<PomodoroRun>,
+    /// 시작할 때마다 증가 → 이전 타이머 태스크는 스스로 종료
+    next_id: u64,
+}
+
+struct PomodoroRun {
+    id: u64,
+    started_at: Instant,
+    total_secs: u32,
+}
+
+impl PomodoroRun {
+    fn remaining_secs(&self) -> u32 {
+        let elapsed = self.started_at.elapsed().as_secs().min(self.total_secs as u64) as u32;
+        self.total_secs - elapsed
+    }
+}
+
+impl PomodoroState {
+    /// 현재 진행 단계
+    pub fn phase(&self) -> PomodoroPhase {
+        match self.0.lock() {
+            Ok(timer) if timer.run.is_some() => PomodoroPhase::Work,
+            _ => PomodoroPhase::Off,
+        }
+    }
+
+    fn status(&self, sessions_today: u32, default_secs: u32) -> PomodoroStatus {
+        let timer = self.0.lock().ok();
+        let run = timer.as_ref().and_then(|timer| timer.run.as_ref());
+        PomodoroStatus {
+            is_active: run.is_some(),
+            remaining_seconds: run.map_or(0, PomodoroRun::remaining_secs),
+            total_seconds: run.map_or(default_secs, |run| run.total_secs),
+            sessions_today,
+        }
+    }
+}
+
+/// 뽀모도로 시작 (진행 중이면 처음부터 다시)
+pub fn start(app: &AppHandle, minutes: u32) -> Result<PomodoroStatus, String> {
+    let total_secs = minutes.max(1) * 60;
+    let id = {
+        let state = app.state::<PomodoroState>();
+        let mut timer = state.0.lock().map_err(|e| e.to_string())?;
+        timer.next_id += 1;
+        let id = timer.next_id;
+        timer.run = Some(PomodoroRun {
+            id,
+            started_at: Instant::now(),
+            total_secs,
+        });
+        id
+    };
+    dnd::refresh(app);
+
+    let status = status(app, total_secs)?;
+    let timer_handle = app.clone();
+    let sessions_today = status.sessions_today;
+    tauri::async_runtime::spawn(async move {
+        run_timer(timer_handle, id, sessions_today).await;
+    });
+    Ok(status)
+}
+
+/// 뽀모도로 중지
+pub fn stop(app: &AppHandle, default_secs: u32) -> Result<PomodoroStatus, String> {
+    let stopped = {
+        let state = app.state::<PomodoroState>();
+        let mut timer = state.0.lock().map_err(|e| e.to_string())?;
+        timer.run.take().is_some()
+    };
+    dnd::refresh(app);
+    if stopped {
+        storage::update(app, |data| {
+            storage::roll_over_day(data);
+            data.today.pomodoro_cancelled += 1;
+        })?;
+    }
+    let status = status(app, default_secs)?;
+    if stopped {
+        let _ = app.emit(event_names::POMODORO_CANCELLED, &status);
+    }
+    Ok(status)
+}
+
+/// 현재 뽀모도로 상태
+pub fn status(app: &AppHandle, default_secs: u32) -> Result<PomodoroStatus, String> {
+    let mut data = storage::load(app)?;
+    storage::roll_over_day(&mut data);
+    Ok(app
+        .state::<PomodoroState>()
+        .status(data.today.pomodoro_sessions, default_secs))
+}
+
+/// 1초마다 남은 시간을 보내고, 끝나면 완료 처리
+async fn run_timer(app: AppHandle, id: u64, sessions_today: u32) {
+    let mut interval = tokio::time::interval(Duration::from_secs(1));
+    loop {
+        interval.tick().await;
+
+        let (remaining, total_secs) = {
+            let state = app.state::<PomodoroState>();
+            let Ok(mut timer) = state.0.lock() else {
+                return;
+            };
+            // 중지되었거나 새로 시작됨
+            let Some(run) = timer.run.as_ref().filter(|run| run.id == id) else {
+                return;
+            };
+            let remaining = run.remaining_secs();
+            let total_secs = run.total_secs;
+            if remaining == 0 {
+                timer.run = None;
+            }
+            (remaining, total_secs)
+        };
+
+        if remaining == 0 {
+            complete(&app, total_secs);
+            return;
+        }
+        let _ = app.emit(
+            event_names::POMODORO_TICK,
+            PomodoroStatus {
+                is_active: true,
+                remaining_seconds: remaining,
+                total_seconds: total_secs,
+                sessions_today,
+            },
+        );
+    }
+}
+
+/// 완료 처리 중 저장한 결과
+struct Completed {
+    sessions_today: u32,
+    level_up: Option<u32>,
+    evolved: Option<EvolutionChanged>,
+    needs: CatNeeds,
+    new_achievements: Vec<Achievement>,
+    new_items: Vec<CosmeticItem>,
+}
+
+/// 완료: 오늘 세션 수 / EXP / 진화 / 욕구 / 업적 / 아이템 기록 → 고양이 축하
+fn complete(app: &AppHandle, total_secs: u32) {
+    dnd::refresh(app);
+    let result = storage::update(app, |data| {
+        storage::roll_over_day(data);
+        let now = chrono::Local::now();
+        data.today.pomodoro_sessions += 1;
+        data.today.exp_gained += EXP_PER_POMODORO;
+        let credited = cats::credited(data, None, now.hour());
+        let (level_up, evolved, new_achievements, new_items) = cats::with_cat(data, &credited, |data| {
+            data.cat.total_pomodoros += 1;
+            growth::touch_streak(data);
+            let level_up = growth::add_exp(&mut data.cat, EXP_PER_POMODORO);
+            needs::apply(&mut data.cat.needs, NeedsActivity::Pomodoro, now.timestamp());
+            let evolved = evolution::evolve(data, now);
+            let new_achievements = achievements::unlock_new(data, now);
+            let new_items = cosmetics::unlock_new(data, now.timestamp());
+            (level_up, evolved, new_achievements, new_items)
+        });
+        Completed {
+            sessions_today: data.today.pomodoro_sessions,
+            level_up,
+            evolved,
+            needs: data.cat.needs.clone(),
+            new_achievements,
+            new_items,
+        }
+    });
+
+    match result {
+        Ok(Completed { sessions_today, level_up, evolved, needs, new_achievements, new_items }) => {
+            let _ = app.emit(
+                event_names::POMODORO_COMPLETE,
+                PomodoroStatus {
+                    is_active: false,
+                    remaining_seconds: 0,
+                    total_seconds: total_secs,
+                    sessions_today,
+                },
+            );
+            let _ = app.emit(
+                event_names::CAT_EXP_GAINED,
+                serde_json::json!({ "amount": EXP_PER_POMODORO, "source": "pomodoro" }),
+            );
+            if let Some(level) = level_up {
+                dnd::emit(app, event_names::CAT_LEVEL_UP, level);
+            }
+            evolution::emit_evolved(app, &evolved);
+            needs::emit_changed(app, &needs);
+            achievements::emit_unlocked(app, &new_achievements);
+            cosmetics::emit_unlocked(app, &new_items);
+        }
+        Err(e) => eprintln!("Failed to save pomodoro session: {}", e),
+    }
+
+    app.state::<CatStateHandle>().send(StateEvent::PomodoroCompleted);
+}
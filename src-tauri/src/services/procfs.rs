@@ -0,0 +1,314 @@
+//! /proc 기반 프로세스 테이블 (Linux 전용)
+//!
+//! `ps`를 매번 fork하는 대신 /proc을 직접 읽는다. 한 번 읽은 PID는 시작 시각 / comm /
+//! 부모가 그대로인 동안 캐시하므로, 틱마다 stat만 읽고 새로 생겼거나 exec / 입양된
+//! 프로세스의 cmdline만 다시 읽는다.
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// 프로세스 한 개의 정보
+#[derive(Debug)]
+pub struct Process {
+    pub pid: u32,
+    /// 실행 파일 이름 (argv[0]의 basename, 예: "code", "idea")
+    pub name: String,
+    /// /proc/<pid>/comm (커널이 15자로 자른 이름)
+    pub comm: String,
+    /// 전체 인자 (argv[0] 포함)
+    pub args: Vec<String>,
+    /// 부모 PID
+    pub ppid: u32,
+    /// 제어 터미널 (0이면 TTY 없음)
+    pub tty_nr: i32,
+}
+
+impl Process {
+    /// 자기 터미널의 포그라운드 작업인지 (Ctrl+Z로 멈춘 에디터 제외)
+    ///
+    /// 포그라운드 프로세스 그룹은 수시로 바뀌므로 캐시하지 않고 매번 읽는다.
+    pub fn is_tty_foreground(&self) -> bool {
+        if self.tty_nr == 0 {
+            return false;
+        }
+        match read_stat(self.pid) {
+            Some(stat) => stat.tpgid > 0 && stat.pgrp == stat.tpgid,
+            None => false,
+        }
+    }
+}
+
+/// /proc/<pid>/stat 중 필요한 필드
+struct Stat {
+    comm: String,
+    ppid: u32,
+    pgrp: i32,
+    tty_nr: i32,
+    tpgid: i32,
+    starttime: u64,
+}
+
+fn read_stat(pid: u32) -> Option<Stat> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // "pid (comm) state ppid pgrp session tty_nr tpgid ..." — comm에 공백/괄호가 있을 수 있음
+    let close = stat.rfind(')')?;
+    let comm = stat.get(stat.find('(')? + 1..close)?.to_string();
+    let fields: Vec<&str> = stat[close + 1..].split_whitespace().collect();
+    Some(Stat {
+        comm,
+        ppid: fields.get(1)?.parse().ok()?,
+        pgrp: fields.get(2)?.parse().ok()?,
+        tty_nr: fields.get(4)?.parse().ok()?,
+        tpgid: fields.get(5)?.parse().ok()?,
+        starttime: fields.get(19)?.parse().ok()?,
+    })
+}
+
+/// 캐시가 유효한지 판단하는 값
+///
+/// PID가 재사용되면 시작 시각이, 같은 프로세스가 exec하면 comm이, 부모가 끝나
+/// init / subreaper에 입양되면 ppid가 바뀐다 (부모 체인 매칭에 쓰므로 캐시하면 안 됨).
+#[derive(Debug, Clone, PartialEq)]
+struct Identity {
+    starttime: u64,
+    comm: String,
+    ppid: u32,
+}
+
+fn read_identity(pid: u32) -> Option<Identity> {
+    let stat = read_stat(pid)?;
+    Some(Identity {
+        starttime: stat.starttime,
+        comm: stat.comm,
+        ppid: stat.ppid,
+    })
+}
+
+/// 캐시 항목 (process가 None: 커널 스레드 등 cmdline이 없는 프로세스)
+struct Cached {
+    identity: Identity,
+    process: Option<Arc<Process>>,
+}
+
+/// PID → 프로세스 캐시
+#[derive(Default)]
+struct ProcessTable {
+    processes: HashMap<u32, Cached>,
+}
+
+impl ProcessTable {
+    fn refresh(&mut self) {
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return;
+        };
+        let pids = entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().to_str().and_then(|n| n.parse::<u32>().ok()));
+        self.update(pids, read_identity, read_process);
+    }
+
+    /// 살아 있는 PID로 캐시 갱신 (새 PID / identity가 바뀐 PID만 다시 읽음)
+    fn update(
+        &mut self,
+        pids: impl Iterator<Item = u32>,
+        identity: impl Fn(u32) -> Option<Identity>,
+        read: impl Fn(u32) -> Option<Process>,
+    ) {
+        let mut alive = HashSet::with_capacity(self.processes.len());
+        for pid in pids {
+            // 읽는 사이에 끝난 프로세스
+            let Some(identity) = identity(pid) else {
+                continue;
+            };
+            alive.insert(pid);
+            if self.processes.get(&pid).is_some_and(|cached| cached.identity == identity) {
+                continue;
+            }
+            let process = read(pid).map(Arc::new);
+            self.processes.insert(pid, Cached { identity, process });
+        }
+
+        self.processes.retain(|pid, _| alive.contains(pid));
+    }
+
+    fn processes(&self) -> impl Iterator<Item = &Arc<Process>> {
+        self.processes.values().filter_map(|cached| cached.process.as_ref())
+    }
+}
+
+fn read_process(pid: u32) -> Option<Process> {
+    let cmdline = fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    let args: Vec<String> = cmdline
+        .split(|b| *b == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect();
+
+    // 커널 스레드 / 좀비는 cmdline이 비어 있음
+    let argv0 = args.first()?;
+    let name = basename(argv0.split(' ').next().unwrap_or(argv0)).to_string();
+    let comm = fs::read_to_string(format!("/proc/{}/comm", pid))
+        .map(|comm| comm.trim_end().to_string())
+        .unwrap_or_default();
+    let stat = read_stat(pid)?;
+
+    Some(Process {
+        pid,
+        name,
+        comm,
+        args,
+        ppid: stat.ppid,
+        tty_nr: stat.tty_nr,
+    })
+}
+
+fn basename(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// `pid`가 `ancestor`의 자손 프로세스인지 (ppid 체인 추적)
+pub fn is_descendant(processes: &HashMap<u32, Arc<Process>>, pid: u32, ancestor: u32) -> bool {
+    ancestors(processes, pid).any(|p| p.pid == ancestor)
+}
+
+/// 부모 → 조부모 순으로 조상 프로세스 순회 (init까지, 순환 방지)
+pub fn ancestors(
+    processes: &HashMap<u32, Arc<Process>>,
+    pid: u32,
+) -> impl Iterator<Item = &Arc<Process>> {
+    let mut current = processes.get(&pid);
+    std::iter::from_fn(move || {
+        let parent = processes.get(&current?.ppid)?;
+        current = Some(parent);
+        Some(parent)
+    })
+    .take(64)
+}
+
+/// PID로 찾기 쉽게 인덱싱
+pub fn by_pid(processes: &[Arc<Process>]) -> HashMap<u32, Arc<Process>> {
+    processes.iter().map(|p| (p.pid, p.clone())).collect()
+}
+
+/// 현재 실행 중인 프로세스 목록
+pub fn processes() -> Vec<Arc<Process>> {
+    static TABLE: OnceLock<Mutex<ProcessTable>> = OnceLock::new();
+
+    let Ok(mut table) = TABLE.get_or_init(Default::default).lock() else {
+        return Vec::new();
+    };
+    table.refresh();
+    table.processes().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn process(pid: u32, args: &[&str]) -> Process {
+        Process {
+            pid,
+            name: basename(args[0]).to_string(),
+            comm: basename(args[0]).to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            ppid: 1,
+            tty_nr: 0,
+        }
+    }
+
+    fn identity(starttime: u64, comm: &str) -> Identity {
+        Identity {
+            starttime,
+            comm: comm.to_string(),
+            ppid: 1,
+        }
+    }
+
+    /// (identity, cmdline) 표로 한 틱 갱신 → 다시 읽은 PID 목록
+    fn tick(table: &mut ProcessTable, procs: &[(u32, Identity, &[&str])]) -> Vec<u32> {
+        let reads = RefCell::new(Vec::new());
+        table.update(
+            procs.iter().map(|(pid, _, _)| *pid),
+            |pid| procs.iter().find(|(p, _, _)| *p == pid).map(|(_, id, _)| id.clone()),
+            |pid| {
+                reads.borrow_mut().push(pid);
+                let (_, id, args) = procs.iter().find(|(p, _, _)| *p == pid)?;
+                (!args.is_empty()).then(|| Process {
+                    ppid: id.ppid,
+                    ..process(pid, args)
+                })
+            },
+        );
+        reads.into_inner()
+    }
+
+    fn names(table: &ProcessTable) -> Vec<(u32, String)> {
+        let mut names: Vec<_> = table.processes().map(|p| (p.pid, p.name.clone())).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn unchanged_pids_are_read_once() {
+        let mut table = ProcessTable::default();
+        let procs: &[(u32, Identity, &[&str])] = &[
+            (10, identity(100, "code"), &["/usr/bin/code"]),
+            (11, identity(5, "kthreadd"), &[]),
+        ];
+        assert_eq!(tick(&mut table, procs), vec![10, 11]);
+        assert_eq!(tick(&mut table, procs), Vec::<u32>::new());
+        assert_eq!(names(&table), vec![(10, "code".to_string())]);
+    }
+
+    #[test]
+    fn reused_pid_with_a_new_command_line_is_reread() {
+        let mut table = ProcessTable::default();
+        tick(&mut table, &[(42, identity(100, "bash"), &["bash", "run.sh"])]);
+
+        // 같은 PID, 다른 시작 시각 → 다른 프로세스
+        let reads = tick(&mut table, &[(42, identity(900, "idea"), &["/opt/idea/bin/idea"])]);
+        assert_eq!(reads, vec![42]);
+        assert_eq!(names(&table), vec![(42, "idea".to_string())]);
+    }
+
+    #[test]
+    fn exec_in_place_is_reread() {
+        let mut table = ProcessTable::default();
+        // fork 직후 (아직 셸) → 같은 시작 시각으로 exec nvim
+        tick(&mut table, &[(7, identity(100, "sh"), &["sh", "-c", "exec nvim"])]);
+        let reads = tick(&mut table, &[(7, identity(100, "nvim"), &["nvim", "main.rs"])]);
+        assert_eq!(reads, vec![7]);
+        assert_eq!(names(&table), vec![(7, "nvim".to_string())]);
+    }
+
+    #[test]
+    fn reparented_process_is_reread() {
+        let mut table = ProcessTable::default();
+        let child = |ppid| Identity { ppid, ..identity(100, "nvim") };
+        tick(&mut table, &[(20, child(10), &["nvim"])]);
+        assert_eq!(tick(&mut table, &[(20, child(10), &["nvim"])]), Vec::<u32>::new());
+
+        // 부모 IDE가 끝나 init에 입양됨
+        assert_eq!(tick(&mut table, &[(20, child(1), &["nvim"])]), vec![20]);
+        assert_eq!(table.processes().map(|p| p.ppid).collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn reads_this_process_from_proc() {
+        let me = read_identity(std::process::id()).expect("no /proc");
+        assert!(me.starttime > 0);
+        assert!(processes().iter().any(|p| p.pid == std::process::id() && p.comm == me.comm));
+    }
+
+    #[test]
+    fn exited_pids_are_dropped() {
+        let mut table = ProcessTable::default();
+        tick(
+            &mut table,
+            &[(1, identity(1, "init"), &["/sbin/init"]), (2, identity(2, "vim"), &["vim"])],
+        );
+        tick(&mut table, &[(1, identity(1, "init"), &["/sbin/init"])]);
+        assert_eq!(names(&table), vec![(1, "init".to_string())]);
+    }
+}
@@ -0,0 +1,138 @@
+//! 현재 작업 중인 프로젝트(등록된 Git 저장소) 판정
+//!
+//! 창 제목 → 에디터 실행 인자 → 작업 디렉토리 순으로 확인한다.
+//! 파일 내용은 읽지 않고 경로와 제목만 비교한다.
+use std::path::{Path, PathBuf};
+
+/// 포커스된 에디터가 작업 중인 저장소 (settings.git_repos에 등록된 경로 그대로 반환)
+#[allow(unused_variables)]
+pub fn detect_project(repos: &[String], pid: Option<u32>, title: Option<&str>) -> Option<String> {
+    if repos.is_empty() {
+        return None;
+    }
+
+    if let Some(repo) = title.and_then(|title| match_title(repos, title)) {
+        return Some(repo);
+    }
+
+    #[cfg(target_os = "linux")]
+    if let Some(pid) = pid {
+        let cwd = std::fs::read_link(format!("/proc/{}/cwd", pid)).ok();
+
+        // 1) 실행 인자 중 경로 (code ~/proj, nvim src/main.rs ...)
+        let args = std::fs::read(format!("/proc/{}/cmdline", pid)).unwrap_or_default();
+        let from_args = args
+            .split(|b| *b == 0)
+            .skip(1)
+            .filter(|arg| !arg.is_empty() && arg[0] != b'-')
+            .map(|arg| PathBuf::from(String::from_utf8_lossy(arg).into_owned()))
+            .map(|path| match &cwd {
+                Some(cwd) if path.is_relative() => cwd.join(path),
+                _ => path,
+            })
+            .find_map(|path| match_path(repos, &path));
+        if from_args.is_some() {
+            return from_args;
+        }
+
+        // 2) 작업 디렉토리
+        if let Some(repo) = cwd.and_then(|cwd| match_path(repos, &cwd)) {
+            return Some(repo);
+        }
+    }
+
+    None
+}
+
+/// 경로가 속한 저장소 (중첩된 경우 가장 깊은 저장소, 등록된 경로 그대로 반환)
+///
+/// 양쪽 모두 정규화한 뒤 경로 단위로 비교하므로 `/a/proj`는 `/a/project2`를 포함하지 않는다.
+pub fn match_path(repos: &[String], path: &Path) -> Option<String> {
+    let path = canonical(path);
+    repos
+        .iter()
+        .map(|repo| (repo, canonical(Path::new(repo))))
+        .filter(|(_, root)| path.starts_with(root))
+        .max_by_key(|(_, root)| root.components().count())
+        .map(|(repo, _)| repo.clone())
+}
+
+/// 정규화한 경로 (없는 경로면 그대로)
+pub fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// 창 제목으로 저장소 판정
+///
+/// - VS Code 계열: "main.rs - commit-cat - Visual Studio Code"
+/// - JetBrains: "commit-cat – main.rs"
+/// - 제목에 전체 경로가 들어 있는 경우 (~/ 축약 포함)
+fn match_title(repos: &[String], title: &str) -> Option<String> {
+    let home = std::env::var("HOME").ok();
+    let separated = [" - ", " – ", " — ", " · ", "[", "]"]
+        .iter()
+        .fold(title.to_string(), |title, sep| title.replace(sep, "\0"));
+    let segments: Vec<&str> = separated
+        .split('\0')
+        .map(|part| part.trim().trim_start_matches('●').trim())
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let by_path = repos
+        .iter()
+        .filter(|repo| {
+            contains_path(title, repo)
+                || home.as_deref().is_some_and(|home| {
+                    repo.strip_prefix(home)
+                        .is_some_and(|rest| contains_path(title, &format!("~{}", rest)))
+                })
+        })
+        .max_by_key(|repo| repo.len());
+    if by_path.is_some() {
+        return by_path.cloned();
+    }
+
+    repos
+        .iter()
+        .find(|repo| {
+            Path::new(repo)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| segments.contains(&name))
+        })
+        .cloned()
+}
+
+/// 제목 안에 경로가 컴포넌트 단위로 들어 있는지 ("/a/project2"는 "/a/proj"가 아님)
+fn contains_path(title: &str, path: &str) -> bool {
+    let path = path.trim_end_matches('/');
+    let in_component = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/');
+    !path.is_empty()
+        && title.match_indices(path).any(|(start, _)| {
+            let before = title[..start].chars().next_back();
+            let after = title[start + path.len()..].chars().next();
+            !before.is_some_and(in_component) && after.is_none_or(|c| c == '/' || !in_component(c))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_paths_match_whole_components() {
+        let repos = vec!["/a/proj".to_string(), "/a/proj/sub".to_string()];
+        let cases = [
+            ("main.rs - /a/proj - Code", Some("/a/proj")),
+            ("vim /a/proj/src/main.rs", Some("/a/proj")),
+            ("/a/proj/sub/lib.rs - Code", Some("/a/proj/sub")),
+            ("main.rs (/a/proj)", Some("/a/proj")),
+            ("main.rs - /a/project2 - Code", None),
+            ("/b/a/proj/main.rs", None),
+            ("/a/proj-old/main.rs", None),
+        ];
+        for (title, expected) in cases {
+            assert_eq!(match_title(&repos, title).as_deref(), expected, "{}", title);
+        }
+    }
+}
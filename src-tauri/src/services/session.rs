@@ -0,0 +1,91 @@
+use crate::models::activity::SessionSnapshot;
+use std::collections::HashMap;
+
+/// 코딩 세션 누적기
+///
+/// 코딩 시간은 초 단위로 모으고, 1분이 찰 때마다 분 단위로 꺼내 저장한다.
+/// 코딩 공백이 `gap_secs`를 넘으면 세션이 끝난 것으로 본다.
+#[derive(Debug, Default)]
+pub struct SessionTracker {
+    session_secs: u64,
+    pending_secs: u64,
+    last_active_at: Option<i64>,
+}
+
+impl SessionTracker {
+    /// 저장된 세션에서 복원 (공백이 길면 새 세션으로 시작, 미적립 초는 유지)
+    pub fn restore(saved: &SessionSnapshot, now: i64, gap_secs: u64) -> Self {
+        let mut tracker = Self {
+            session_secs: saved.session_seconds,
+            pending_secs: saved.pending_seconds,
+            last_active_at: saved.last_active_at,
+        };
+        tracker.end_if_stale(now, gap_secs);
+        tracker
+    }
+
+    /// 한 틱 기록
+    pub fn record(&mut self, coding: bool, secs: u64, now: i64, gap_secs: u64) {
+        self.end_if_stale(now, gap_secs);
+        if coding {
+            self.session_secs += secs;
+            self.pending_secs += secs;
+            self.last_active_at = Some(now);
+        }
+    }
+
+    fn end_if_stale(&mut self, now: i64, gap_secs: u64) {
+        let stale = self
+            .last_active_at
+            .is_none_or(|last| now.saturating_sub(last) > gap_secs as i64);
+        if stale {
+            self.session_secs = 0;
+        }
+    }
+
+    /// 적립할 분 (남은 초는 다음 틱으로)
+    pub fn take_minutes(&mut self) -> u32 {
+        let minutes = self.pending_secs / 60;
+        self.pending_secs %= 60;
+        minutes as u32
+    }
+
+    pub fn session_minutes(&self) -> u32 {
+        (self.session_secs / 60) as u32
+    }
+
+    pub fn snapshot(&self) -> SessionSnapshot {
+        SessionSnapshot {
+            session_seconds: self.session_secs,
+            pending_seconds: self.pending_secs,
+            last_active_at: self.last_active_at,
+        }
+    }
+}
+
+/// 키(저장소, 언어 ...)별 코딩 시간 누적기
+///
+/// 틱 단위 초를 모았다가 1분이 찰 때마다 분 단위로 꺼내 저장한다.
+#[derive(Debug, Default)]
+pub struct MinuteBuckets {
+    pending_secs: HashMap<String, u64>,
+}
+
+impl MinuteBuckets {
+    pub fn add(&mut self, key: &str, secs: u64) {
+        *self.pending_secs.entry(key.to_string()).or_default() += secs;
+    }
+
+    /// 1분 이상 쌓인 키의 분을 꺼냄 (남은 초는 유지)
+    pub fn take_minutes(&mut self) -> Vec<(String, u32)> {
+        self.pending_secs
+            .iter_mut()
+            .filter(|(_, secs)| **secs >= 60)
+            .map(|(key, secs)| {
+                let minutes = *secs / 60;
+                *secs %= 60;
+                (key.clone(), minutes as u32)
+            })
+            .collect()
+    }
+}
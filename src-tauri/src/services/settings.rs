@@ -0,0 +1,32 @@
+use crate::models::settings::AppSettings;
+use tokio::sync::watch;
+
+/// 실행 중 공유되는 설정 상태
+///
+/// `update_settings` 커맨드가 값을 교체하면 구독 중인 백그라운드 서비스(활동 모니터 등)가
+/// 다음 틱을 기다리지 않고 바로 새 설정을 반영한다.
+pub struct SettingsState {
+    tx: watch::Sender<AppSettings>,
+}
+
+impl SettingsState {
+    pub fn new(initial: AppSettings) -> Self {
+        let (tx, _rx) = watch::channel(initial);
+        Self { tx }
+    }
+
+    /// 현재 설정 스냅샷
+    pub fn current(&self) -> AppSettings {
+        self.tx.borrow().clone()
+    }
+
+    /// 설정 변경 구독
+    pub fn subscribe(&self) -> watch::Receiver<AppSettings> {
+        self.tx.subscribe()
+    }
+
+    /// 설정 교체 후 구독자에게 알림
+    pub fn replace(&self, settings: AppSettings) {
+        self.tx.send_replace(settings);
+    }
+}
@@ -1,72 +1,631 @@
-use crate::models::cat::CatState;
-use chrono::Local;
-
-/// 상태 전환 조건 (초 단위 기준값)
-pub const IDLE_TO_SLEEPING_SECS: u64 = 600;    // 10분 무활동 → 수면
-pub const CODING_TO_IDLE_SECS: u64 = 180;      // 3분 무활동 → idle
-pub const CELEBRATING_DURATION_SECS: u64 = 5;  // 5초 후 idle 복귀
-pub const FRUSTRATED_DURATION_SECS: u64 = 5;   // 5초 후 idle 복귀
+use crate::models::behavior::{
+    default_behavior, BehaviorSpec, EventKind, Guard, IdleSetting, IdleThreshold, PomodoroPhase,
+};
+use crate::models::cat::{CatMood, CatState, ClickKind};
+use crate::models::settings::{in_hours, valid_hours, AppSettings};
+use std::collections::{HashMap, VecDeque};
 
 /// 상태 전환 입력 이벤트
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StateEvent {
     ActivityDetected,
     IdleTimeout(u64),    // 유휴 시간 (초)
     CommitDetected,
     ErrorDetected,
-    UserClicked,
-    TimerExpired,        // Celebrating/Frustrated 자동 복귀
+    UserClicked(ClickKind),
+    PomodoroCompleted,
+    BuildFixed,          // 실패했던 빌드 / 테스트 통과
+    LateNightCoding(u32), // 오늘 밤 코딩 시간 (분), 밤 코딩 알림 기준을 넘을 때마다
+    Overworked,          // 쉬라는 알림 (오래 연속 코딩 / 하루 한도 / 연속 밤 코딩)
+    TimerExpired,        // 임시 상태 자동 복귀
+}
+
+impl StateEvent {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            StateEvent::ActivityDetected => EventKind::ActivityDetected,
+            StateEvent::IdleTimeout(_) => EventKind::IdleTimeout,
+            StateEvent::CommitDetected => EventKind::CommitDetected,
+            StateEvent::ErrorDetected => EventKind::ErrorDetected,
+            StateEvent::UserClicked(_) => EventKind::UserClicked,
+            StateEvent::PomodoroCompleted => EventKind::PomodoroCompleted,
+            StateEvent::BuildFixed => EventKind::BuildFixed,
+            StateEvent::LateNightCoding(_) => EventKind::LateNightCoding,
+            StateEvent::Overworked => EventKind::Overworked,
+            StateEvent::TimerExpired => EventKind::TimerExpired,
+        }
+    }
+}
+
+/// 전환 조건 판단에 쓰는 외부 상태 (설정 + 현재 시각 + 성장 + 뽀모도로)
+#[derive(Debug, Clone)]
+pub struct TransitionContext {
+    pub hour: u32,
+    /// 설정된 밤 시간대인지
+    pub is_night: bool,
+    /// 코딩 → Idle 유휴 기준 (초)
+    pub idle_secs: u64,
+    /// → Sleeping 유휴 기준 (초)
+    pub sleep_secs: u64,
+    pub level: u32,
+    pub streak_days: u32,
+    pub pomodoro: PomodoroPhase,
+    /// 쉬라는 알림 뒤 아직 충분히 쉬지 않음
+    pub needs_break: bool,
+    /// 방해 금지 중
+    pub quiet: bool,
 }
 
-/// 상태 전환 로직
-pub fn transition(current: &CatState, event: &StateEvent) -> Option<CatState> {
-    match (current, event) {
-        // ── Idle ──
-        (CatState::Idle, StateEvent::ActivityDetected) => Some(CatState::Coding),
-        (CatState::Idle, StateEvent::IdleTimeout(secs)) if *secs >= IDLE_TO_SLEEPING_SECS => {
-            if is_night_time() {
-                Some(CatState::Sleeping)
-            } else {
-                Some(CatState::Sleeping)
+impl TransitionContext {
+    /// 설정 + 시각만으로 만든 기본 조건 (레벨 1, 스트릭 0, 뽀모도로 없음)
+    pub fn from_settings(settings: &AppSettings, hour: u32) -> Self {
+        Self {
+            hour,
+            is_night: settings.is_night_hour(hour),
+            idle_secs: settings.idle_threshold_seconds,
+            sleep_secs: settings.sleep_threshold_seconds,
+            level: 1,
+            streak_days: 0,
+            pomodoro: PomodoroPhase::Off,
+            needs_break: false,
+            quiet: false,
+        }
+    }
+}
+
+/// 행동 정의를 검증한 상태 머신
+pub struct StateMachine {
+    spec: BehaviorSpec,
+    /// 상태 이름 → spec.states 인덱스
+    states: HashMap<String, usize>,
+}
+
+impl StateMachine {
+    /// 행동 정의 검증
+    ///
+    /// 없는 상태 참조, 시작 상태에서 닿을 수 없는 상태, 빠져나갈 규칙이 없는 상태,
+    /// 복귀 타이머로 빠져나갈 수 없는 임시 상태가 있으면 에러
+    pub fn compile(spec: BehaviorSpec) -> Result<Self, String> {
+        let mut states = HashMap::new();
+        for (index, state) in spec.states.iter().enumerate() {
+            if state.name.trim().is_empty() || state.name == "*" {
+                return Err(format!("Invalid state name: {:?}", state.name));
+            }
+            if states.insert(state.name.clone(), index).is_some() {
+                return Err(format!("Duplicate state: {}", state.name));
+            }
+            if state.timeout_secs == Some(0) {
+                return Err(format!("State {} has a zero timeout", state.name));
             }
         }
-        (CatState::Idle, StateEvent::UserClicked) => Some(CatState::Interaction),
+        if !states.contains_key(&spec.initial) {
+            return Err(format!("Unknown initial state: {}", spec.initial));
+        }
 
-        // ── Coding ──
-        (CatState::Coding, StateEvent::CommitDetected) => Some(CatState::Celebrating),
-        (CatState::Coding, StateEvent::ErrorDetected) => Some(CatState::Frustrated),
-        (CatState::Coding, StateEvent::IdleTimeout(secs)) if *secs >= CODING_TO_IDLE_SECS => {
-            Some(CatState::Idle)
+        for (index, rule) in spec.transitions.iter().enumerate() {
+            if rule.from.is_empty() {
+                return Err(format!("Transition #{} has no source state", index + 1));
+            }
+            let unknown = rule
+                .from
+                .iter()
+                .chain(rule.to.iter())
+                .find(|name| *name != "*" && !states.contains_key(*name));
+            if let Some(name) = unknown {
+                return Err(format!("Transition #{} refers to unknown state: {}", index + 1, name));
+            }
+            if rule.to.as_deref() == Some("*") {
+                return Err(format!("Transition #{} cannot target \"*\"", index + 1));
+            }
+            if let Some(hours) = rule.guard.hours {
+                valid_hours(hours).map_err(|e| format!("Transition #{}: {}", index + 1, e))?;
+            }
         }
-        (CatState::Coding, StateEvent::UserClicked) => Some(CatState::Interaction),
 
-        // ── Celebrating → Idle ──
-        (CatState::Celebrating, StateEvent::TimerExpired) => Some(CatState::Idle),
+        let machine = Self { spec, states };
+        machine.check_reachable()?;
+        machine.check_exits()?;
+        Ok(machine)
+    }
+
+    /// 기본 내장 행동
+    pub fn builtin() -> Self {
+        Self::compile(default_behavior()).expect("built-in cat behavior is valid")
+    }
 
-        // ── Frustrated → Idle ──
-        (CatState::Frustrated, StateEvent::TimerExpired) => Some(CatState::Idle),
+    pub fn initial(&self) -> CatState {
+        CatState::new(&self.spec.initial)
+    }
 
-        // ── Sleeping → Idle ──
-        (CatState::Sleeping, StateEvent::ActivityDetected) => Some(CatState::Idle),
-        (CatState::Sleeping, StateEvent::UserClicked) => Some(CatState::Idle),
+    /// 상태 전환 (전환 없으면 None)
+    ///
+    /// 규칙은 파일 순서대로 비교하며 처음 일치한 규칙이 적용된다.
+    pub fn transition(&self, current: &CatState, event: &StateEvent, ctx: &TransitionContext) -> Option<CatState> {
+        let kind = event.kind();
+        let rule = self.spec.transitions.iter().find(|rule| {
+            from_matches(&rule.from, current.as_str())
+                && (rule.on == kind || rule.on == EventKind::Any)
+                && guard_matches(&rule.guard, event, ctx)
+        })?;
 
-        // ── Interaction → Idle ──
-        (CatState::Interaction, StateEvent::TimerExpired) => Some(CatState::Idle),
+        let next = rule.to.as_deref()?;
+        (next != current.as_str()).then(|| CatState::new(next))
+    }
 
-        // ── Tired (밤 시간 코딩) ──
-        (CatState::Coding, StateEvent::IdleTimeout(_)) if is_night_time() => {
-            Some(CatState::Tired)
+    /// 임시 상태의 유지 시간 (초)
+    pub fn timeout_secs(&self, state: &CatState) -> Option<u64> {
+        self.states
+            .get(state.as_str())
+            .and_then(|&index| self.spec.states[index].timeout_secs)
+    }
+
+    /// 저장된 상태에서 재시작
+    ///
+    /// 임시 상태는 복귀 타이머가 사라졌고, 정의에서 빠진 상태는 갈 곳이 없으므로 시작 상태로
+    pub fn restore(&self, saved: &CatState) -> CatState {
+        if self.states.contains_key(saved.as_str()) && self.timeout_secs(saved).is_none() {
+            saved.clone()
+        } else {
+            self.initial()
         }
-        (CatState::Tired, StateEvent::ActivityDetected) => Some(CatState::Coding),
+    }
 
-        _ => None, // 전환 없음
+    /// 상태별 표정 (모르는 상태면 Happy)
+    pub fn mood(&self, state: &CatState) -> CatMood {
+        self.states
+            .get(state.as_str())
+            .map_or(CatMood::Happy, |&index| self.spec.states[index].mood.clone())
     }
+
+    /// 모든 상태에 나가는 규칙이 있는지, 임시 상태는 타이머로 나갈 수 있는지
+    fn check_exits(&self) -> Result<(), String> {
+        for state in &self.spec.states {
+            let name = state.name.as_str();
+            let has_exit = self.spec.transitions.iter().any(|rule| {
+                from_matches(&rule.from, name) && rule.to.as_deref().is_some_and(|to| to != name)
+            });
+            if !has_exit {
+                return Err(format!("State {} has no exit", name));
+            }
+
+            if state.timeout_secs.is_some() {
+                // 조건 없이 timerExpired를 받는 첫 규칙이 다른 상태로 보내야 함
+                let on_timer = self.spec.transitions.iter().find(|rule| {
+                    from_matches(&rule.from, name)
+                        && matches!(rule.on, EventKind::TimerExpired | EventKind::Any)
+                        && rule.guard == Guard::default()
+                });
+                let leaves = on_timer
+                    .and_then(|rule| rule.to.as_deref())
+                    .is_some_and(|to| to != name);
+                if !leaves {
+                    return Err(format!("Timed state {} never leaves on timerExpired", name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 시작 상태에서 모든 상태에 닿을 수 있는지
+    fn check_reachable(&self) -> Result<(), String> {
+        let mut reached = vec![false; self.spec.states.len()];
+        let mut queue = VecDeque::from([self.spec.initial.as_str()]);
+        reached[self.states[&self.spec.initial]] = true;
+
+        while let Some(name) = queue.pop_front() {
+            for rule in &self.spec.transitions {
+                let Some(to) = rule.to.as_deref() else {
+                    continue;
+                };
+                let index = self.states[to];
+                if from_matches(&rule.from, name) && !reached[index] {
+                    reached[index] = true;
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        let unreachable: Vec<&str> = self
+            .spec
+            .states
+            .iter()
+            .zip(&reached)
+            .filter(|(_, reached)| !**reached)
+            .map(|(state, _)| state.name.as_str())
+            .collect();
+        if unreachable.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Unreachable states: {}", unreachable.join(", ")))
+        }
+    }
+}
+
+fn from_matches(from: &[String], state: &str) -> bool {
+    from.iter().any(|name| name == "*" || name == state)
 }
 
-/// 밤 시간 판정 (기본: 23시 ~ 6시)
-fn is_night_time() -> bool {
-    let hour = Local::now().hour();
-    hour >= 23 || hour < 6
+fn guard_matches(guard: &Guard, event: &StateEvent, ctx: &TransitionContext) -> bool {
+    if guard.night.is_some_and(|night| night != ctx.is_night) {
+        return false;
+    }
+    if guard.hours.is_some_and(|hours| !in_hours(hours, ctx.hour)) {
+        return false;
+    }
+    if let Some(threshold) = guard.min_idle {
+        let min_secs = match threshold {
+            IdleThreshold::Setting(IdleSetting::Idle) => ctx.idle_secs,
+            IdleThreshold::Setting(IdleSetting::Sleep) => ctx.sleep_secs,
+            IdleThreshold::Seconds(secs) => secs,
+        };
+        match event {
+            StateEvent::IdleTimeout(secs) if *secs >= min_secs => {}
+            _ => return false,
+        }
+    }
+    if let Some(kind) = guard.click {
+        match event {
+            StateEvent::UserClicked(clicked) if *clicked == kind => {}
+            _ => return false,
+        }
+    }
+    if guard.min_level.is_some_and(|level| ctx.level < level) {
+        return false;
+    }
+    if guard.min_streak.is_some_and(|streak| ctx.streak_days < streak) {
+        return false;
+    }
+    if guard.needs_break.is_some_and(|needs_break| needs_break != ctx.needs_break) {
+        return false;
+    }
+    if guard.quiet.is_some_and(|quiet| quiet != ctx.quiet) {
+        return false;
+    }
+    guard.pomodoro.is_none_or(|phase| phase == ctx.pomodoro)
 }
 
-use chrono::Timelike;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::behavior::{StateSpec, TransitionSpec};
+
+    const STATES: [&str; 7] = [
+        "idle",
+        "coding",
+        "celebrating",
+        "frustrated",
+        "sleeping",
+        "tired",
+        "interaction",
+    ];
+
+    fn state(name: &str) -> CatState {
+        CatState::new(name)
+    }
+
+    /// 기본 설정 기준: 유휴 300초, 수면 600초
+    fn events() -> Vec<StateEvent> {
+        vec![
+            StateEvent::ActivityDetected,
+            StateEvent::IdleTimeout(10),
+            StateEvent::IdleTimeout(300),
+            StateEvent::IdleTimeout(600),
+            StateEvent::CommitDetected,
+            StateEvent::ErrorDetected,
+            StateEvent::UserClicked(ClickKind::Single),
+            StateEvent::UserClicked(ClickKind::Double),
+            StateEvent::UserClicked(ClickKind::LongPress),
+            StateEvent::UserClicked(ClickKind::Drag),
+            StateEvent::PomodoroCompleted,
+            StateEvent::BuildFixed,
+            StateEvent::LateNightCoding(30),
+            StateEvent::Overworked,
+            StateEvent::TimerExpired,
+        ]
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum When {
+        Always,
+        Day,
+        Night,
+    }
+
+    /// 기본 행동에서 전환이 일어나는 모든 (상태, 이벤트, 시간대) 조합 — 여기에 없으면 전환 없음
+    fn table() -> Vec<(&'static str, StateEvent, When, &'static str)> {
+        use StateEvent::*;
+        use When::*;
+        let mut rows = Vec::new();
+
+        // 임시 상태는 타이머로만 복귀
+        for from in ["celebrating", "frustrated", "interaction"] {
+            rows.push((from, TimerExpired, Always, "idle"));
+        }
+
+        // 깨어 있는 상태의 즉시 반응
+        for from in ["idle", "coding", "tired"] {
+            rows.push((from, CommitDetected, Always, "celebrating"));
+            rows.push((from, PomodoroCompleted, Always, "celebrating"));
+            rows.push((from, BuildFixed, Always, "celebrating"));
+            rows.push((from, ErrorDetected, Always, "frustrated"));
+            for kind in [ClickKind::Single, ClickKind::Double, ClickKind::LongPress, ClickKind::Drag] {
+                rows.push((from, UserClicked(kind), Always, "interaction"));
+            }
+        }
+
+        // 잠든 상태: 커밋 / 뽀모도로 / 빌드 복구는 축하, 클릭 / 활동은 깨우기, 에러 / 쓰다듬기는 무시
+        rows.push(("sleeping", CommitDetected, Always, "celebrating"));
+        rows.push(("sleeping", PomodoroCompleted, Always, "celebrating"));
+        rows.push(("sleeping", BuildFixed, Always, "celebrating"));
+        for kind in [ClickKind::Single, ClickKind::Double, ClickKind::Drag] {
+            rows.push(("sleeping", UserClicked(kind), Always, "idle"));
+        }
+        rows.push(("sleeping", ActivityDetected, Always, "idle"));
+
+        // 유휴
+        rows.push(("idle", IdleTimeout(300), Night, "sleeping"));
+        rows.push(("idle", IdleTimeout(600), Always, "sleeping"));
+        for from in ["coding", "tired"] {
+            rows.push((from, IdleTimeout(300), Always, "idle"));
+            rows.push((from, IdleTimeout(600), Always, "sleeping"));
+        }
+
+        // 밤 코딩 / 쉬라는 알림 / 활동 재개
+        rows.push(("coding", LateNightCoding(30), Night, "tired"));
+        rows.push(("idle", Overworked, Always, "tired"));
+        rows.push(("coding", Overworked, Always, "tired"));
+        rows.push(("idle", ActivityDetected, Always, "coding"));
+        rows.push(("tired", ActivityDetected, Day, "coding"));
+
+        rows
+    }
+
+    fn expected(from: &str, event: &StateEvent, night: bool) -> Option<CatState> {
+        table()
+            .into_iter()
+            .find(|(state, on, when, _)| {
+                *state == from
+                    && on == event
+                    && match when {
+                        When::Always => true,
+                        When::Day => !night,
+                        When::Night => night,
+                    }
+            })
+            .map(|(_, _, _, to)| state(to))
+    }
+
+    #[test]
+    fn every_state_and_event_matches_the_table() {
+        let machine = StateMachine::builtin();
+        let settings = AppSettings::default();
+        for (night, hour) in [(false, 14), (true, 2)] {
+            let ctx = TransitionContext::from_settings(&settings, hour);
+            assert_eq!(ctx.is_night, night);
+            for from in STATES {
+                for event in events() {
+                    assert_eq!(
+                        machine.transition(&state(from), &event, &ctx),
+                        expected(from, &event, night),
+                        "{} + {:?} (night: {})",
+                        from,
+                        event,
+                        night
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn table_rows_are_reachable() {
+        let machine = StateMachine::builtin();
+        let settings = AppSettings::default();
+        for (from, event, when, to) in table() {
+            let hour = if when == When::Night { 2 } else { 14 };
+            let ctx = TransitionContext::from_settings(&settings, hour);
+            assert_eq!(
+                machine.transition(&state(from), &event, &ctx),
+                Some(state(to)),
+                "{} + {:?}",
+                from,
+                event
+            );
+        }
+    }
+
+    #[test]
+    fn night_window_follows_settings() {
+        let machine = StateMachine::builtin();
+        let settings = AppSettings {
+            night_hour_start: 1,
+            night_hour_end: 5,
+            ..AppSettings::default()
+        };
+        let at = |hour| TransitionContext::from_settings(&settings, hour);
+        let late = StateEvent::LateNightCoding(30);
+
+        assert_eq!(machine.transition(&state("coding"), &late, &at(23)), None);
+        assert_eq!(machine.transition(&state("coding"), &late, &at(3)), Some(state("tired")));
+        assert_eq!(machine.transition(&state("tired"), &StateEvent::ActivityDetected, &at(3)), None);
+        assert_eq!(
+            machine.transition(&state("tired"), &StateEvent::ActivityDetected, &at(5)),
+            Some(state("coding"))
+        );
+    }
+
+    #[test]
+    fn overworked_cat_stays_tired_until_a_break() {
+        let machine = StateMachine::builtin();
+        let settings = AppSettings::default();
+        let mut ctx = TransitionContext::from_settings(&settings, 14);
+        let coding = StateEvent::ActivityDetected;
+
+        assert_eq!(machine.transition(&state("coding"), &StateEvent::Overworked, &ctx), Some(state("tired")));
+        ctx.needs_break = true;
+        assert_eq!(machine.transition(&state("tired"), &coding, &ctx), None);
+        assert_eq!(
+            machine.transition(&state("tired"), &StateEvent::IdleTimeout(300), &ctx),
+            Some(state("idle"))
+        );
+        ctx.needs_break = false;
+        assert_eq!(machine.transition(&state("tired"), &coding, &ctx), Some(state("coding")));
+    }
+
+    #[test]
+    fn quiet_mode_skips_celebrations() {
+        let machine = StateMachine::builtin();
+        let settings = AppSettings::default();
+        let mut ctx = TransitionContext::from_settings(&settings, 14);
+        ctx.quiet = true;
+
+        for event in [StateEvent::CommitDetected, StateEvent::PomodoroCompleted, StateEvent::BuildFixed] {
+            for from in ["idle", "coding", "sleeping"] {
+                assert_eq!(machine.transition(&state(from), &event, &ctx), None, "{} + {:?}", from, event);
+            }
+        }
+        assert_eq!(
+            machine.transition(&state("coding"), &StateEvent::ErrorDetected, &ctx),
+            Some(state("frustrated"))
+        );
+    }
+
+    #[test]
+    fn idle_thresholds_follow_settings() {
+        let machine = StateMachine::builtin();
+        let settings = AppSettings {
+            idle_threshold_seconds: 60,
+            sleep_threshold_seconds: 120,
+            ..AppSettings::default()
+        };
+        let ctx = TransitionContext::from_settings(&settings, 14);
+        let idle = |secs| StateEvent::IdleTimeout(secs);
+
+        assert_eq!(machine.transition(&state("coding"), &idle(59), &ctx), None);
+        assert_eq!(machine.transition(&state("coding"), &idle(60), &ctx), Some(state("idle")));
+        assert_eq!(machine.transition(&state("idle"), &idle(60), &ctx), None);
+        assert_eq!(machine.transition(&state("idle"), &idle(120), &ctx), Some(state("sleeping")));
+    }
+
+    #[test]
+    fn temporary_states_time_out_and_restore_to_initial() {
+        let machine = StateMachine::builtin();
+        for name in STATES {
+            let temporary = matches!(name, "celebrating" | "frustrated" | "interaction");
+            assert_eq!(machine.timeout_secs(&state(name)).is_some(), temporary, "{}", name);
+            let restored = machine.restore(&state(name));
+            assert_eq!(restored, if temporary { state("idle") } else { state(name) });
+        }
+        assert_eq!(machine.restore(&state("removed")), state("idle"));
+    }
+
+    /// 작은 정의: idle ⇄ coding (+ 추가 규칙)
+    fn spec_with(extra_states: &[(&str, Option<u64>)], extra_rules: serde_json::Value) -> BehaviorSpec {
+        let mut spec: BehaviorSpec = serde_json::from_value(serde_json::json!({
+            "initial": "idle",
+            "states": [
+                { "name": "idle", "mood": "happy" },
+                { "name": "coding", "mood": "focused" }
+            ],
+            "transitions": [
+                { "from": ["idle"], "on": "activityDetected", "to": "coding" },
+                { "from": ["coding"], "on": "idleTimeout", "to": "idle", "guard": { "minIdle": "idle" } }
+            ]
+        }))
+        .unwrap();
+        for (name, timeout_secs) in extra_states {
+            spec.states.push(StateSpec {
+                name: name.to_string(),
+                mood: CatMood::Happy,
+                timeout_secs: *timeout_secs,
+            });
+        }
+        let extra: Vec<TransitionSpec> = serde_json::from_value(extra_rules).unwrap();
+        spec.transitions.extend(extra);
+        spec
+    }
+
+    #[test]
+    fn new_behaviors_can_be_added_from_the_file() {
+        let spec = spec_with(
+            &[("hungry", None), ("playful", Some(10))],
+            serde_json::json!([
+                { "from": ["idle"], "on": "idleTimeout", "to": "hungry",
+                  "guard": { "minIdle": 3600, "hours": [11, 14] } },
+                { "from": ["hungry"], "on": "userClicked", "to": null, "guard": { "click": "drag" } },
+                { "from": ["hungry"], "on": "userClicked", "to": "idle" },
+                { "from": ["idle"], "on": "userClicked", "to": "playful",
+                  "guard": { "minLevel": 5, "minStreak": 3, "pomodoro": "off" } },
+                { "from": ["playful"], "on": "timerExpired", "to": "idle" }
+            ]),
+        );
+        let machine = StateMachine::compile(spec).unwrap();
+        let settings = AppSettings::default();
+        let mut ctx = TransitionContext::from_settings(&settings, 12);
+
+        assert_eq!(machine.transition(&state("idle"), &StateEvent::IdleTimeout(3599), &ctx), None);
+        assert_eq!(
+            machine.transition(&state("idle"), &StateEvent::IdleTimeout(3600), &ctx),
+            Some(state("hungry"))
+        );
+        ctx.hour = 15;
+        assert_eq!(machine.transition(&state("idle"), &StateEvent::IdleTimeout(3600), &ctx), None);
+        assert_eq!(machine.transition(&state("hungry"), &StateEvent::UserClicked(ClickKind::Drag), &ctx), None);
+        assert_eq!(
+            machine.transition(&state("hungry"), &StateEvent::UserClicked(ClickKind::Double), &ctx),
+            Some(state("idle"))
+        );
+
+        assert_eq!(machine.transition(&state("idle"), &StateEvent::UserClicked(ClickKind::Single), &ctx), None);
+        ctx.level = 5;
+        ctx.streak_days = 3;
+        assert_eq!(
+            machine.transition(&state("idle"), &StateEvent::UserClicked(ClickKind::Single), &ctx),
+            Some(state("playful"))
+        );
+        ctx.pomodoro = PomodoroPhase::Work;
+        assert_eq!(machine.transition(&state("idle"), &StateEvent::UserClicked(ClickKind::Single), &ctx), None);
+        assert_eq!(machine.timeout_secs(&state("playful")), Some(10));
+    }
+
+    #[test]
+    fn invalid_definitions_are_rejected() {
+        let cases = [
+            (
+                spec_with(&[], serde_json::json!([{ "from": ["idle"], "on": "commitDetected", "to": "party" }])),
+                "unknown state",
+            ),
+            (spec_with(&[("hungry", None)], serde_json::json!([])), "Unreachable"),
+            (
+                spec_with(&[("hungry", None)], serde_json::json!([{ "from": ["idle"], "on": "idleTimeout", "to": "hungry" }])),
+                "no exit",
+            ),
+            (
+                spec_with(
+                    &[("playful", Some(10))],
+                    serde_json::json!([
+                        { "from": ["idle"], "on": "userClicked", "to": "playful" },
+                        { "from": ["playful"], "on": "userClicked", "to": "idle" }
+                    ]),
+                ),
+                "timerExpired",
+            ),
+        ];
+        for (spec, message) in cases {
+            let error = StateMachine::compile(spec).err().expect("invalid spec");
+            assert!(error.contains(message), "{}", error);
+        }
+
+        let mut spec = spec_with(&[], serde_json::json!([]));
+        spec.initial = "missing".to_string();
+        assert!(StateMachine::compile(spec).is_err());
+    }
+
+    #[test]
+    fn builtin_behavior_round_trips_through_json() {
+        let json = serde_json::to_string(&default_behavior()).unwrap();
+        let spec: BehaviorSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(spec, default_behavior());
+        assert!(StateMachine::compile(spec).is_ok());
+    }
+}
@@ -1,8 +1,15 @@
+use crate::models::activity::DailySummary;
+use crate::models::behavior::{default_behavior, BehaviorSpec};
+use crate::models::ide::{default_ide_rules, IdeRule};
 use crate::models::settings::AppData;
+use crate::services::cat_state::CatStateHandle;
 use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
 use tauri::{AppHandle, Manager};
 
 const DATA_FILE: &str = "commit-cat-data.json";
+const IDE_RULES_FILE: &str = "ide-rules.json";
+const BEHAVIOR_FILE: &str = "cat-behavior.json";
 
 /// 앱 데이터 디렉토리 경로
 fn data_dir(app: &AppHandle) -> Result<PathBuf, String> {
@@ -29,11 +36,52 @@ pub fn init(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
         let json = serde_json::to_string_pretty(&default_data)?;
         std::fs::write(&path, json)?;
     }
+
+    // IDE 감지 규칙: 사용자가 직접 편집할 수 있도록 기본값을 파일로 꺼내 둔다
+    let rules_path = dir.join(IDE_RULES_FILE);
+    if !rules_path.exists() {
+        let json = serde_json::to_string_pretty(&default_ide_rules())?;
+        std::fs::write(&rules_path, json)?;
+    }
+
+    // 고양이 행동 정의: 상태 / 전환 규칙도 파일로 꺼내 둔다
+    let behavior_path = dir.join(BEHAVIOR_FILE);
+    if !behavior_path.exists() {
+        let json = serde_json::to_string_pretty(&default_behavior())?;
+        std::fs::write(&behavior_path, json)?;
+    }
     Ok(())
 }
 
+/// data.json 읽기 / 쓰기를 직렬화 (모니터가 매분 저장하는 동안 읽어도 안전하도록)
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> Result<MutexGuard<'static, ()>, String> {
+    LOCK.lock().map_err(|e| format!("Storage lock poisoned: {}", e))
+}
+
 /// 데이터 로드
 pub fn load(app: &AppHandle) -> Result<AppData, String> {
+    let _guard = lock()?;
+    read(app)
+}
+
+/// 데이터 읽기-수정-쓰기 (여러 서비스가 동시에 저장해도 덮어쓰지 않도록 직렬화)
+pub fn update<T>(app: &AppHandle, f: impl FnOnce(&mut AppData) -> T) -> Result<T, String> {
+    let _guard = lock()?;
+
+    let mut data = read(app)?;
+    let result = f(&mut data);
+    write(app, &data)?;
+    // 상태 머신 전환 조건이 매 이벤트마다 디스크를 읽지 않도록
+    if let Some(cat_state) = app.try_state::<CatStateHandle>() {
+        cat_state.set_progress(&data.cat);
+    }
+    Ok(result)
+}
+
+/// 데이터 읽기 (잠금은 호출하는 쪽에서)
+fn read(app: &AppHandle) -> Result<AppData, String> {
     let path = data_path(app)?;
     let content = std::fs::read_to_string(&path)
         .map_err(|e| format!("Failed to read data: {}", e))?;
@@ -41,15 +89,64 @@ pub fn load(app: &AppHandle) -> Result<AppData, String> {
         .map_err(|e| format!("Failed to parse data: {}", e))
 }
 
-/// 데이터 저장
-pub fn save(app: &AppHandle, data: &AppData) -> Result<(), String> {
+/// 데이터 저장: 임시 파일에 쓴 뒤 rename → 중간에 죽어도 반쯤 쓴 파일이 남지 않음
+fn write(app: &AppHandle, data: &AppData) -> Result<(), String> {
     let path = data_path(app)?;
     let json = serde_json::to_string_pretty(data)
         .map_err(|e| format!("Failed to serialize: {}", e))?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)
+        .map_err(|e| format!("Failed to write: {}", e))?;
+    std::fs::rename(&tmp, &path)
+        .map_err(|e| format!("Failed to write: {}", e))
+}
+
+/// 날짜가 바뀌었으면 today를 history 맨 앞으로 옮기고 오늘 날짜로 새로 시작
+pub fn roll_over_day(data: &mut AppData) -> bool {
+    let today = crate::utils::today_string();
+    if data.today.date == today {
+        return false;
+    }
+
+    if !data.today.date.is_empty() {
+        let previous = std::mem::take(&mut data.today);
+        data.history.insert(0, previous);
+        cleanup_history(data);
+    }
+    data.today = DailySummary {
+        date: today,
+        ..Default::default()
+    };
+    true
+}
+
+/// IDE 감지 규칙 로드
+pub fn load_ide_rules(app: &AppHandle) -> Result<Vec<IdeRule>, String> {
+    let path = data_dir(app)?.join(IDE_RULES_FILE);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read IDE rules: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse IDE rules: {}", e))
+}
+
+/// IDE 감지 규칙 저장
+pub fn save_ide_rules(app: &AppHandle, rules: &[IdeRule]) -> Result<(), String> {
+    let path = data_dir(app)?.join(IDE_RULES_FILE);
+    let json = serde_json::to_string_pretty(rules)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
     std::fs::write(&path, json)
         .map_err(|e| format!("Failed to write: {}", e))
 }
 
+/// 고양이 행동 정의 로드
+pub fn load_behavior(app: &AppHandle) -> Result<BehaviorSpec, String> {
+    let path = data_dir(app)?.join(BEHAVIOR_FILE);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read cat behavior: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse cat behavior: {}", e))
+}
+
 /// History 관리: 90일 초과 데이터 정리
 pub fn cleanup_history(data: &mut AppData) {
     if data.history.len() > 90 {
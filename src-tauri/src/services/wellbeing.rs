@@ -0,0 +1,306 @@
+//! 쉬어 가기 (연속 코딩 / 하루 코딩 시간 / 연속 밤 코딩)
+//!
+//! 기준을 넘으면 시스템 알림으로 부드럽게 알리고 고양이를 tired로 보낸다.
+//! 쉬라는 알림 뒤에는 충분히 쉴 때까지 코딩을 다시 시작해도 tired로 남는다.
+use crate::events::event_names;
+use crate::models::settings::{AppData, AppSettings};
+use crate::models::wellbeing::WellbeingReminder;
+use crate::services::cat_state::CatStateHandle;
+use crate::services::dnd;
+use crate::services::state_machine::StateEvent;
+use chrono::NaiveDate;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Manager};
+
+/// 하루 한도를 넘긴 코딩 시간의 EXP 비율 (%)
+pub const OVER_LIMIT_EXP_PERCENT: u32 = 50;
+/// 밤 코딩이 이만큼 넘으면 "밤을 새운 날"로 셈 (분)
+pub const LATE_NIGHT_MIN_MINUTES: u32 = 30;
+
+/// 쉬라고 알린 뒤 아직 쉬지 않았는지 (상태 머신 조건 `needsBreak`)
+#[derive(Default)]
+pub struct WellbeingState(AtomicBool);
+
+impl WellbeingState {
+    pub fn needs_break(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn set_needs_break(&self, needs_break: bool) {
+        self.0.store(needs_break, Ordering::Relaxed);
+    }
+}
+
+/// 연속 코딩 추적 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stretch {
+    /// 쉬라고 알릴 차례
+    Remind(WellbeingReminder),
+    /// 코딩 뒤 충분히 쉼 → 연속 코딩 초기화
+    Rested,
+}
+
+/// 연속 코딩 시간 (짧은 쉼은 이어서 셈)
+#[derive(Debug, Default)]
+pub struct StretchTracker {
+    coding_secs: u64,
+    reminders: u32,
+}
+
+impl StretchTracker {
+    /// 한 틱 기록 → 알릴 차례거나 충분히 쉬었으면 결과
+    ///
+    /// `idle_seconds`: 마지막 입력 이후 시간 (이만큼 쉰 것으로 봄)
+    pub fn record(&mut self, settings: &AppSettings, coding: bool, idle_seconds: u64, secs: u64) -> Option<Stretch> {
+        if !coding {
+            if self.coding_secs == 0 || idle_seconds < settings.break_minutes as u64 * 60 {
+                return None;
+            }
+            *self = Self::default();
+            return Some(Stretch::Rested);
+        }
+
+        self.coding_secs += secs;
+        if !settings.break_reminders || settings.break_reminder_minutes == 0 {
+            return None;
+        }
+        let minutes = (self.coding_secs / 60) as u32;
+        if minutes < settings.break_reminder_minutes * (self.reminders + 1) {
+            return None;
+        }
+        self.reminders += 1;
+        Some(Stretch::Remind(WellbeingReminder::LongStretch { minutes }))
+    }
+}
+
+/// 하루 한도를 넘긴 분만큼 EXP 줄이기 (설정이 꺼져 있으면 그대로)
+///
+/// `before`: 이번 저장 전 오늘 코딩 시간, `minutes` / `exp`: 이번에 저장할 코딩 시간 / EXP
+pub fn healthy_exp(settings: &AppSettings, before: u32, minutes: u32, exp: u32) -> u32 {
+    let limit = settings.daily_limit_minutes;
+    if !settings.reduce_exp_over_limit || limit == 0 || minutes == 0 {
+        return exp;
+    }
+    let over = (before + minutes).saturating_sub(limit.max(before));
+    exp - exp * over * (100 - OVER_LIMIT_EXP_PERCENT) / (minutes * 100)
+}
+
+/// 이번 저장으로 오늘 코딩 시간이 하루 한도를 넘었으면 알림
+pub fn daily_limit(settings: &AppSettings, before: u32, after: u32) -> Option<WellbeingReminder> {
+    let limit = settings.daily_limit_minutes;
+    (settings.break_reminders && limit > 0 && before < limit && after >= limit).then_some(
+        WellbeingReminder::DailyLimit {
+            minutes: after,
+            limit_minutes: limit,
+        },
+    )
+}
+
+/// 오늘 밤 코딩이 기준을 막 넘었고, 연속 밤 수가 설정 이상이면 알림
+///
+/// `before`: 이번 저장 전 오늘 밤 코딩 시간 (오늘 요약은 이미 갱신된 상태로 부른다)
+pub fn late_night_streak(data: &AppData, before: u32) -> Option<WellbeingReminder> {
+    let settings = &data.settings;
+    let streak_nights = settings.late_night_streak_nights;
+    if !settings.break_reminders
+        || streak_nights == 0
+        || before >= LATE_NIGHT_MIN_MINUTES
+        || data.today.late_night_minutes < LATE_NIGHT_MIN_MINUTES
+    {
+        return None;
+    }
+
+    let today = NaiveDate::parse_from_str(&data.today.date, "%Y-%m-%d").ok()?;
+    let late_night = |date: NaiveDate| {
+        let date = date.format("%Y-%m-%d").to_string();
+        data.history
+            .iter()
+            .find(|day| day.date == date)
+            .is_some_and(|day| day.late_night_minutes >= LATE_NIGHT_MIN_MINUTES)
+    };
+    let (mut nights, mut date) = (1, today);
+    while let Some(previous) = date.pred_opt().filter(|previous| late_night(*previous)) {
+        nights += 1;
+        date = previous;
+    }
+    (nights >= streak_nights).then_some(WellbeingReminder::LateNightStreak { nights })
+}
+
+/// 쉬라고 알림: 시스템 알림 + `wellbeing:reminder` (방해 금지 중이면 나중에) + 고양이 tired
+pub fn remind(app: &AppHandle, reminder: &WellbeingReminder) {
+    app.state::<WellbeingState>().set_needs_break(true);
+    let (title, body) = message(reminder);
+    dnd::notify(app, &title, &body);
+    dnd::emit(app, event_names::WELLBEING_REMINDER, reminder);
+    app.state::<CatStateHandle>().send(StateEvent::Overworked);
+}
+
+/// 충분히 쉼 → tired에서 벗어날 수 있음
+pub fn rested(app: &AppHandle) {
+    app.state::<WellbeingState>().set_needs_break(false);
+}
+
+fn message(reminder: &WellbeingReminder) -> (String, String) {
+    match reminder {
+        WellbeingReminder::LongStretch { minutes } => (
+            "Time for a stretch 🐾".to_string(),
+            format!("You've been coding for {} minutes straight. Your cat is taking a nap — join them for a few minutes?", minutes),
+        ),
+        WellbeingReminder::DailyLimit { minutes, .. } => (
+            "That's a full day 😿".to_string(),
+            format!("{}h {}m of coding today. Your cat thinks the rest can wait until tomorrow.", minutes / 60, minutes % 60),
+        ),
+        WellbeingReminder::LateNightStreak { nights } => (
+            "Another late night 🌙".to_string(),
+            format!("This is night {} in a row. Your cat is yawning — maybe an early night today?", nights),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::activity::DailySummary;
+    use crate::services::storage;
+
+    fn settings() -> AppSettings {
+        AppSettings {
+            break_reminders: true,
+            reduce_exp_over_limit: true,
+            daily_limit_minutes: 480,
+            late_night_streak_nights: 3,
+            ..AppSettings::default()
+        }
+    }
+
+    #[test]
+    fn exp_is_halved_past_the_daily_limit() {
+        // (저장 전 오늘 코딩 분, 이번 분, EXP, 결과)
+        let cases = [
+            (400, 60, 60, 60),
+            (420, 60, 60, 60), // 딱 한도까지
+            (450, 60, 60, 45), // 30분만 초과
+            (480, 60, 60, 30),
+            (500, 60, 60, 30),
+            (479, 1, 10, 10),
+            (480, 1, 10, 5),
+            (600, 0, 10, 10),
+        ];
+        for (before, minutes, exp, expected) in cases {
+            assert_eq!(healthy_exp(&settings(), before, minutes, exp), expected, "{} + {}", before, minutes);
+        }
+
+        let off = AppSettings {
+            reduce_exp_over_limit: false,
+            ..settings()
+        };
+        let no_limit = AppSettings {
+            daily_limit_minutes: 0,
+            ..settings()
+        };
+        assert_eq!(healthy_exp(&off, 500, 60, 60), 60);
+        assert_eq!(healthy_exp(&no_limit, 500, 60, 60), 60);
+    }
+
+    #[test]
+    fn daily_limit_fires_once_when_crossed() {
+        let reminder = |minutes| {
+            Some(WellbeingReminder::DailyLimit {
+                minutes,
+                limit_minutes: 480,
+            })
+        };
+        assert_eq!(daily_limit(&settings(), 470, 479), None);
+        assert_eq!(daily_limit(&settings(), 470, 480), reminder(480));
+        assert_eq!(daily_limit(&settings(), 479, 500), reminder(500));
+        assert_eq!(daily_limit(&settings(), 480, 490), None);
+
+        let off = AppSettings {
+            break_reminders: false,
+            ..settings()
+        };
+        assert_eq!(daily_limit(&off, 470, 480), None);
+    }
+
+    fn night(date: &str, late_night_minutes: u32) -> DailySummary {
+        DailySummary {
+            date: date.to_string(),
+            late_night_minutes,
+            ..DailySummary::default()
+        }
+    }
+
+    #[test]
+    fn late_night_streak_counts_consecutive_nights() {
+        // (오늘, 지난 기록, 결과) — 오늘 밤 코딩이 기준(30분)을 막 넘은 순간
+        type Case<'a> = (&'a str, &'a [(&'a str, u32)], Option<u32>);
+        let cases: &[Case] = &[
+            ("2026-03-01", &[("2026-02-28", 40), ("2026-02-27", 30)], Some(3)),
+            ("2026-01-01", &[("2025-12-31", 90), ("2025-12-30", 45), ("2025-12-29", 31)], Some(4)),
+            // 기준 미만인 밤 / 빠진 날에서 끊김
+            ("2026-03-01", &[("2026-02-28", 40), ("2026-02-27", 29), ("2026-02-26", 60)], None),
+            ("2026-03-01", &[("2026-02-28", 40), ("2026-02-26", 60)], None),
+        ];
+        for (today, history, expected) in cases {
+            let data = AppData {
+                settings: settings(),
+                today: night(today, 30),
+                history: history.iter().map(|(date, minutes)| night(date, *minutes)).collect(),
+                ..AppData::default()
+            };
+            let nights = late_night_streak(&data, 0).map(|reminder| match reminder {
+                WellbeingReminder::LateNightStreak { nights } => nights,
+                other => panic!("unexpected {:?}", other),
+            });
+            assert_eq!(nights, *expected, "{} {:?}", today, history);
+        }
+    }
+
+    #[test]
+    fn late_night_streak_survives_the_day_rolling_over() {
+        let today = chrono::Local::now().date_naive();
+        let date = |days_ago: i64| (today - chrono::Duration::days(days_ago)).format("%Y-%m-%d").to_string();
+        let mut data = AppData {
+            settings: settings(),
+            today: night(&date(1), 50),
+            history: vec![night(&date(2), 35)],
+            ..AppData::default()
+        };
+
+        // 어제 요약이 기록으로 넘어간 뒤 오늘 밤 코딩이 기준을 넘음
+        assert!(storage::roll_over_day(&mut data));
+        data.today.late_night_minutes = 20;
+        assert_eq!(late_night_streak(&data, 0), None);
+        data.today.late_night_minutes = 30;
+        assert_eq!(late_night_streak(&data, 20), Some(WellbeingReminder::LateNightStreak { nights: 3 }));
+        // 이미 기준을 넘긴 밤에는 다시 알리지 않음
+        data.today.late_night_minutes = 45;
+        assert_eq!(late_night_streak(&data, 30), None);
+    }
+
+    #[test]
+    fn a_real_break_ends_the_stretch() {
+        let settings = AppSettings {
+            break_reminder_minutes: 50,
+            break_minutes: 5,
+            ..settings()
+        };
+        let mut tracker = StretchTracker::default();
+
+        // 50분 연속 코딩 → 알림, 짧은 쉼은 이어서 셈
+        for _ in 0..49 {
+            assert_eq!(tracker.record(&settings, true, 0, 60), None);
+        }
+        assert_eq!(tracker.record(&settings, false, 120, 60), None);
+        let reminded = tracker.record(&settings, true, 0, 60);
+        assert_eq!(reminded, Some(Stretch::Remind(WellbeingReminder::LongStretch { minutes: 50 })));
+
+        // 충분히 쉬어야 rested
+        assert_eq!(tracker.record(&settings, false, 4 * 60, 60), None);
+        assert_eq!(tracker.record(&settings, false, 5 * 60, 60), Some(Stretch::Rested));
+
+        // 쉰 뒤에는 처음부터 다시 셈, 코딩 없이 쉬기만 하면 아무 일 없음
+        assert_eq!(tracker.record(&settings, false, 10 * 60, 60), None);
+        assert_eq!(tracker.record(&settings, true, 0, 60), None);
+    }
+}
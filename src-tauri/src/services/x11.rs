@@ -0,0 +1,353 @@
+//! X11 연결 (Linux 전용)
+//!
+//! libX11 / libXss는 런타임에 dlopen으로 불러오므로 Wayland 전용 세션이나
+//! 라이브러리가 없는 환경에서도 앱은 정상 실행되고, 감지 기능만 비활성화된다.
+use std::ffi::CString;
+use std::os::raw::{c_int, c_long, c_uchar, c_ulong, c_void};
+use std::sync::{Mutex, OnceLock};
+use x11_dl::xlib::{self, Atom, Display, Window, XErrorEvent, Xlib};
+use x11_dl::xss::Xss;
+
+/// 포커스된 최상위 창 정보
+#[derive(Debug, Clone)]
+pub struct ActiveWindow {
+    pub window: u64,
+    /// WM_CLASS의 class 부분 (예: "Code", "jetbrains-idea")
+    pub class: Option<String>,
+    /// WM_CLASS의 instance 부분 (예: "code")
+    pub instance: Option<String>,
+    /// _NET_WM_PID
+    pub pid: Option<u32>,
+    /// 창 제목 (_NET_WM_NAME, 없으면 WM_NAME)
+    pub title: Option<String>,
+}
+
+/// 열린 디스플레이 연결 + 로드된 라이브러리
+pub struct X11Connection {
+    xlib: Xlib,
+    xss: Option<Xss>,
+    display: *mut Display,
+}
+
+// 디스플레이 포인터는 전역 Mutex 안에서만 접근하므로 스레드 간 이동해도 안전
+unsafe impl Send for X11Connection {}
+
+impl X11Connection {
+    /// `$DISPLAY`로 연결. X 서버가 없으면 None
+    fn open() -> Option<Self> {
+        let xlib = Xlib::open().ok()?;
+        let display = unsafe { (xlib.XOpenDisplay)(std::ptr::null()) };
+        if display.is_null() {
+            return None;
+        }
+
+        // XScreenSaver 확장은 선택 사항
+        let xss = Xss::open().ok().filter(|xss| {
+            let (mut event_base, mut error_base): (c_int, c_int) = (0, 0);
+            unsafe { (xss.XScreenSaverQueryExtension)(display, &mut event_base, &mut error_base) != 0 }
+        });
+
+        Some(Self { xlib, xss, display })
+    }
+
+    /// 마지막 키보드/마우스 입력 이후 경과 시간 (밀리초)
+    pub fn idle_millis(&self) -> Option<u64> {
+        let xss = self.xss.as_ref()?;
+        unsafe {
+            let info = (xss.XScreenSaverAllocInfo)();
+            if info.is_null() {
+                return None;
+            }
+            let root = (self.xlib.XDefaultRootWindow)(self.display);
+            let status = (xss.XScreenSaverQueryInfo)(self.display, root, info);
+            #[allow(clippy::unnecessary_cast)] // c_ulong: 32비트에서는 u32
+            let idle = (*info).idle as u64;
+            (self.xlib.XFree)(info as *mut c_void);
+            (status != 0).then_some(idle)
+        }
+    }
+}
+
+impl X11Connection {
+    /// 요청 묶음 실행
+    ///
+    /// 에러 핸들러는 프로세스 전체(GDK 포함)가 공유하므로, 그동안만 트랩을 걸고
+    /// 끝나면 XSync로 에러를 모두 받은 뒤 원래 핸들러로 되돌린다.
+    fn trapped<T>(&self, f: impl FnOnce(&Self) -> T) -> T {
+        let previous = unsafe { (self.xlib.XSetErrorHandler)(Some(trap_x_error)) };
+        if let Ok(mut trap) = ERROR_TRAP.lock() {
+            *trap = Some(ErrorTrap {
+                display: self.display as usize,
+                previous,
+            });
+        }
+
+        let result = f(self);
+
+        unsafe {
+            (self.xlib.XSync)(self.display, xlib::False);
+            (self.xlib.XSetErrorHandler)(previous);
+        }
+        if let Ok(mut trap) = ERROR_TRAP.lock() {
+            *trap = None;
+        }
+        result
+    }
+
+    fn atom(&self, name: &str) -> Atom {
+        let name = CString::new(name).expect("atom name contains NUL");
+        unsafe { (self.xlib.XInternAtom)(self.display, name.as_ptr(), xlib::False) }
+    }
+
+    fn root(&self) -> Window {
+        unsafe { (self.xlib.XDefaultRootWindow)(self.display) }
+    }
+
+    /// 창 속성 원본 조회 → (format, 항목 수, 데이터 포인터)
+    ///
+    /// 반환된 포인터는 호출 측에서 XFree 해야 한다.
+    fn raw_property(&self, window: Window, property: &str) -> Option<(c_int, usize, *mut c_uchar)> {
+        let property = self.atom(property);
+        let mut actual_type: Atom = 0;
+        let mut actual_format: c_int = 0;
+        let mut items: c_ulong = 0;
+        let mut bytes_after: c_ulong = 0;
+        let mut data: *mut c_uchar = std::ptr::null_mut();
+
+        let status = unsafe {
+            (self.xlib.XGetWindowProperty)(
+                self.display,
+                window,
+                property,
+                0,
+                1024,
+                xlib::False,
+                xlib::AnyPropertyType as c_ulong,
+                &mut actual_type,
+                &mut actual_format,
+                &mut items,
+                &mut bytes_after,
+                &mut data,
+            )
+        };
+
+        if status != xlib::Success as c_int || data.is_null() {
+            return None;
+        }
+        if items == 0 {
+            unsafe { (self.xlib.XFree)(data as *mut c_void) };
+            return None;
+        }
+        Some((actual_format, items as usize, data))
+    }
+
+    /// format 32 속성 (ATOM / CARDINAL / WINDOW 배열)
+    ///
+    /// Xlib은 format 32 값을 C `long` 배열로 돌려준다 (64비트에서는 8바이트).
+    pub fn property_u32s(&self, window: Window, property: &str) -> Option<Vec<u64>> {
+        let (format, items, data) = self.raw_property(window, property)?;
+        let values = (format == 32).then(|| unsafe {
+            std::slice::from_raw_parts(data as *const c_long, items)
+                .iter()
+                .map(|v| *v as u64)
+                .collect()
+        });
+        unsafe { (self.xlib.XFree)(data as *mut c_void) };
+        values
+    }
+
+    /// format 8 속성 (STRING / UTF8_STRING)
+    pub fn property_bytes(&self, window: Window, property: &str) -> Option<Vec<u8>> {
+        let (format, items, data) = self.raw_property(window, property)?;
+        let bytes = (format == 8).then(|| unsafe { std::slice::from_raw_parts(data, items).to_vec() });
+        unsafe { (self.xlib.XFree)(data as *mut c_void) };
+        bytes
+    }
+
+    /// EWMH `_NET_ACTIVE_WINDOW` (데스크톱 / 포커스 없음이면 None)
+    fn active_window_id(&self) -> Option<u64> {
+        let window = *self.property_u32s(self.root(), "_NET_ACTIVE_WINDOW")?.first()?;
+        (window != 0).then_some(window)
+    }
+
+    /// 포커스된 창이 풀스크린인지 (EWMH `_NET_WM_STATE`에 `_NET_WM_STATE_FULLSCREEN`)
+    pub fn active_window_fullscreen(&self) -> bool {
+        let Some(window) = self.active_window_id() else {
+            return false;
+        };
+        let fullscreen = self.atom("_NET_WM_STATE_FULLSCREEN");
+        self.property_u32s(window, "_NET_WM_STATE")
+            .is_some_and(|states| states.contains(&fullscreen))
+    }
+
+    /// EWMH `_NET_ACTIVE_WINDOW` 기준 포커스된 창
+    pub fn active_window(&self) -> Option<ActiveWindow> {
+        let window = self.active_window_id()?;
+
+        // WM_CLASS = "instance\0class\0"
+        let (instance, class) = match self.property_bytes(window, "WM_CLASS") {
+            Some(bytes) => {
+                let mut parts = bytes
+                    .split(|b| *b == 0)
+                    .filter(|part| !part.is_empty())
+                    .map(|part| String::from_utf8_lossy(part).into_owned());
+                (parts.next(), parts.next())
+            }
+            None => (None, None),
+        };
+
+        let pid = self
+            .property_u32s(window, "_NET_WM_PID")
+            .and_then(|values| values.first().map(|pid| *pid as u32));
+
+        Some(ActiveWindow {
+            window,
+            class,
+            instance,
+            pid,
+            title: self.window_title(window),
+        })
+    }
+
+    /// 창 제목 (_NET_WM_NAME, 없으면 WM_NAME)
+    fn window_title(&self, window: Window) -> Option<String> {
+        self.property_bytes(window, "_NET_WM_NAME")
+            .or_else(|| self.property_bytes(window, "WM_NAME"))
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// EWMH `_NET_CLIENT_LIST` 기준 열린 최상위 창 제목 전부
+    pub fn window_titles(&self) -> Vec<String> {
+        self.property_u32s(self.root(), "_NET_CLIENT_LIST")
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|window| self.window_title(window))
+            .collect()
+    }
+}
+
+type ErrorHandler = Option<unsafe extern "C" fn(*mut Display, *mut XErrorEvent) -> c_int>;
+
+/// 요청 묶음 동안 걸린 트랩 (디스플레이 포인터는 비교용 주소로만 보관)
+struct ErrorTrap {
+    display: usize,
+    previous: ErrorHandler,
+}
+
+static ERROR_TRAP: Mutex<Option<ErrorTrap>> = Mutex::new(None);
+
+/// 조회 도중 창이 닫히는 경우(BadWindow)가 흔하므로 우리 연결의 BadWindow만 무시하고,
+/// 나머지 에러는 원래 핸들러로 넘긴다 (기본 핸들러는 프로세스를 종료시킨다)
+unsafe extern "C" fn trap_x_error(display: *mut Display, event: *mut XErrorEvent) -> c_int {
+    let (ours, previous) = match ERROR_TRAP.lock() {
+        Ok(trap) => trap
+            .as_ref()
+            .map_or((false, None), |trap| (trap.display == display as usize, trap.previous)),
+        Err(_) => (false, None),
+    };
+    if ours && (*event).error_code == xlib::BadWindow {
+        return 0;
+    }
+    match previous {
+        Some(handler) => handler(display, event),
+        None => 0,
+    }
+}
+
+impl Drop for X11Connection {
+    fn drop(&mut self) {
+        unsafe {
+            (self.xlib.XCloseDisplay)(self.display);
+        }
+    }
+}
+
+fn connection() -> &'static Mutex<Option<X11Connection>> {
+    static CONNECTION: OnceLock<Mutex<Option<X11Connection>>> = OnceLock::new();
+    CONNECTION.get_or_init(|| Mutex::new(None))
+}
+
+/// 공유 X11 연결로 작업 실행
+///
+/// 연결이 없으면 매 호출마다 다시 시도하므로, 앱 시작 후 X 세션이 준비되어도 따라잡는다.
+pub fn with_connection<T>(f: impl FnOnce(&X11Connection) -> Option<T>) -> Option<T> {
+    let mut guard = connection().lock().ok()?;
+    if guard.is_none() {
+        *guard = X11Connection::open();
+    }
+    let conn = guard.as_ref()?;
+    conn.trapped(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 창 관리자 없이 EWMH 속성을 직접 써서 확인 (xvfb-run cargo test -- --ignored)
+    #[test]
+    #[ignore = "needs an X server, e.g. xvfb-run"]
+    fn fullscreen_follows_net_wm_state_of_the_active_window() {
+        let conn = X11Connection::open().expect("no X server ($DISPLAY)");
+        let root = conn.root();
+        let window = unsafe { (conn.xlib.XCreateSimpleWindow)(conn.display, root, 0, 0, 100, 100, 0, 0, 0) };
+
+        let set_atoms = |target: Window, property: &str, kind: &str, values: &[c_ulong]| unsafe {
+            (conn.xlib.XChangeProperty)(
+                conn.display,
+                target,
+                conn.atom(property),
+                conn.atom(kind),
+                32,
+                xlib::PropModeReplace,
+                values.as_ptr() as *const c_uchar,
+                values.len() as c_int,
+            );
+            (conn.xlib.XSync)(conn.display, xlib::False);
+        };
+
+        set_atoms(root, "_NET_ACTIVE_WINDOW", "WINDOW", &[window]);
+        set_atoms(window, "_NET_WM_STATE", "ATOM", &[conn.atom("_NET_WM_STATE_ABOVE")]);
+        assert!(!conn.active_window_fullscreen());
+
+        set_atoms(window, "_NET_WM_STATE", "ATOM", &[conn.atom("_NET_WM_STATE_FULLSCREEN")]);
+        assert!(conn.active_window_fullscreen());
+
+        set_atoms(root, "_NET_ACTIVE_WINDOW", "WINDOW", &[0]);
+        assert!(!conn.active_window_fullscreen());
+
+        unsafe {
+            (conn.xlib.XDestroyWindow)(conn.display, window);
+        }
+    }
+
+    static OUTER_ERRORS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+    unsafe extern "C" fn outer_handler(_display: *mut Display, _event: *mut XErrorEvent) -> c_int {
+        OUTER_ERRORS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        0
+    }
+
+    #[test]
+    #[ignore = "needs an X server, e.g. xvfb-run"]
+    fn trap_swallows_bad_window_and_restores_the_previous_handler() {
+        let conn = X11Connection::open().expect("no X server ($DISPLAY)");
+        unsafe {
+            (conn.xlib.XSetErrorHandler)(Some(outer_handler));
+        }
+
+        // 없는 창 → BadWindow는 트랩이 삼킨다
+        assert!(conn.trapped(|conn| conn.property_bytes(0x7fff_fff0, "WM_NAME")).is_none());
+        assert_eq!(OUTER_ERRORS.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        // BadWindow가 아닌 에러(없는 atom → BadAtom)는 원래 핸들러로
+        conn.trapped(|conn| unsafe {
+            (conn.xlib.XGetAtomName)(conn.display, 0x7fff_fff0);
+        });
+        assert_eq!(OUTER_ERRORS.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // 끝나면 원래 핸들러로 되돌아와 있다
+        let current = unsafe { (conn.xlib.XSetErrorHandler)(None) };
+        let expected: ErrorHandler = Some(outer_handler);
+        assert_eq!(current.map(|handler| handler as usize), expected.map(|handler| handler as usize));
+    }
+}
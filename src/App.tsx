@@ -1,4 +1,5 @@
 import { useEffect, useRef } from "react";
+import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { Cat } from "./components/cat/Cat";
 import { useCatStore } from "./stores/catStore";
@@ -6,8 +7,38 @@ import { useCatStore } from "./stores/catStore";
 // 백엔드에서 오는 상태 데이터
 interface ActivityStatus {
   isIdeRunning: boolean;
+  isCoding: boolean;
   activeIde: string | null;
+  runningIdes: string[];
+  focusedApp: string | null;
+  project: string | null;
+  language: string | null;
   idleSeconds: number;
+  // 직전 보고 대비 바뀐 필드
+  changed: string[];
+}
+
+// 밤 코딩 알림
+interface LateNightCoding {
+  hour: number;
+  minutes: number;
+  // 피곤함 단계 (1 = 가벼운 알림)
+  level: number;
+}
+
+// 백엔드 상태 머신 전환
+interface CatStateChanged {
+  state: string;
+  previous: string;
+}
+
+// 방해 금지 상태
+interface DndStatus {
+  active: boolean;
+  reasons: ("manual" | "quietHours" | "pomodoro" | "screenSharing")[];
+  manualUntil: number | null;
+  sharingApp: string | null;
+  queued: number;
 }
 
 function App() {
@@ -19,7 +50,16 @@ function App() {
   // 코딩 시간 카운터 (1분마다)
   const codingTimer = useRef<ReturnType<typeof setInterval> | null>(null);
 
+  // 방해 금지 중에는 축하 애니메이션 생략
+  const quiet = useRef(false);
+
   useEffect(() => {
+    invoke<DndStatus>("get_dnd_status")
+      .then((status) => {
+        quiet.current = status.active;
+      })
+      .catch(() => {});
+
     const unlisten = Promise.all([
       // ── IDE 감지됨 → coding ──
       listen<string>("activity:ide-detected", (event) => {
@@ -63,7 +103,7 @@ function App() {
       }),
 
       // ── 밤 코딩 → tired ──
-      listen<number>("activity:late-night-coding", () => {
+      listen<LateNightCoding>("activity:late-night-coding", () => {
         // coding 상태에서만 tired로
         const current = useCatStore.getState().state;
         if (current === "coding") {
@@ -71,7 +111,7 @@ function App() {
         }
       }),
 
-      // ── 주기적 상태 보고 (초기 상태 동기화 포함) ──
+      // ── 상태 변화 보고 (초기 상태 동기화 포함) ──
       listen<ActivityStatus>("activity:status", (event) => {
         const status = event.payload;
         setIdleSeconds(status.idleSeconds);
@@ -97,6 +137,7 @@ function App() {
 
       // ── Git 커밋 → celebrating (임시) ──
       listen("git:new-commit", () => {
+        if (quiet.current) return;
         if (tempStateTimer.current) clearTimeout(tempStateTimer.current);
         setState("celebrating");
         tempStateTimer.current = setTimeout(() => {
@@ -106,6 +147,16 @@ function App() {
         }, 3000);
       }),
 
+      // ── 백엔드 상태 머신 전환 ──
+      listen<CatStateChanged>("cat:state-changed", (event) => {
+        setState(event.payload.state);
+      }),
+
+      // ── 방해 금지 ──
+      listen<DndStatus>("dnd:changed", (event) => {
+        quiet.current = event.payload.active;
+      }),
+
       // ── 풀스크린 ──
       listen<boolean>("activity:fullscreen", (event) => {
         const el = document.getElementById("root");
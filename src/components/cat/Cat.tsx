@@ -1,5 +1,6 @@
 import { useState, useRef, useEffect, useCallback } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { LogicalPosition } from "@tauri-apps/api/dpi";
 import { useCatStore } from "../../stores/catStore";
@@ -36,10 +37,35 @@ function pickRandomBehavior(): IdleBehavior {
 
 function randomInRange(min: number, max: number) { return min + Math.random() * (max - min); }
 
+// ── 클릭 반응 (백엔드 interaction 서비스가 고름) ──
+type ClickKind = "single" | "double" | "longPress" | "drag";
+
+interface CatReaction {
+  kind: ClickKind;
+  reaction: string;
+  emoji: string;
+  message: string;
+  affectionGained: number;
+  affection: number;
+  annoyed: boolean;
+}
+
+// ── 꾸미기 (get_inventory) ──
+type CosmeticSlot = "hat" | "collar" | "fur" | "accessory";
+
+interface CosmeticItem {
+  id: string;
+  slot: CosmeticSlot;
+  // 이모지 (털 색은 CSS 클래스 이름)
+  icon: string;
+  equipped: boolean;
+}
+
+// 더블클릭 대기 / 길게 누르기 판정 시간
+const DOUBLE_CLICK_MS = 250;
+const LONG_PRESS_MS = 600;
+
 // ── 메시지 ──
-const normalMessages = ["meow!", "nya~", "purr...", "mrrp?", "*stretch*", "code with me~", "prrrr~"];
-const happyMessages = ["😻 love it!", "more pets!", "purrrr~", "nya nya~!"];
-const annoyedMessages = ["...meow.", "okay okay!", "I'm busy!", "stahp!", "😾"];
 const autoMessages = ["*yawn*", "...", "💭", "hmm...", "*tail swish*", "commit something!", "☕", "*purr*"];
 
 // ── 윈도우 크기 ──
@@ -70,9 +96,13 @@ export function Cat() {
   const [bubbleKey, setBubbleKey] = useState(0);
   const bubbleTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
 
-  // ── 클릭 카운터 ──
-  const clickCount = useRef(0);
-  const clickResetTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+  // ── 클릭 / 길게 누르기 ──
+  const clickTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+  const longPressTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+  const didLongPress = useRef(false);
+
+  // ── 착용 아이템 ──
+  const [outfit, setOutfit] = useState<CosmeticItem[]>([]);
 
   // ── 이모지 ──
   const [displayEmoji, setDisplayEmoji] = useState("🐱");
@@ -224,8 +254,17 @@ export function Cat() {
   const handleMouseDown = useCallback((e: React.MouseEvent) => {
     setIsDragging(true);
     didDrag.current = false;
+    didLongPress.current = false;
     dragStartMouse.current = { x: e.screenX, y: e.screenY };
     dragStartWin.current = { ...winPosRef.current };
+
+    // 움직이지 않고 누르고 있으면 쓰다듬기
+    if (longPressTimer.current) clearTimeout(longPressTimer.current);
+    longPressTimer.current = setTimeout(() => {
+      if (didDrag.current) return;
+      didLongPress.current = true;
+      touchCat("longPress");
+    }, LONG_PRESS_MS);
   }, []);
 
   useEffect(() => {
@@ -233,6 +272,7 @@ export function Cat() {
 
     const handleMove = (e: MouseEvent) => {
       didDrag.current = true;
+      if (longPressTimer.current) clearTimeout(longPressTimer.current);
       const dx = e.screenX - dragStartMouse.current.x;
       const dy = e.screenY - dragStartMouse.current.y;
       moveWindow(dragStartWin.current.x + dx, dragStartWin.current.y + dy);
@@ -240,8 +280,9 @@ export function Cat() {
 
     const handleUp = () => {
       setIsDragging(false);
+      if (longPressTimer.current) clearTimeout(longPressTimer.current);
       if (didDrag.current) {
-        showBubble("wheee~!", 1500);
+        touchCat("drag");
       }
     };
 
@@ -251,43 +292,71 @@ export function Cat() {
       window.removeEventListener("mousemove", handleMove);
       window.removeEventListener("mouseup", handleUp);
     };
-  }, [isDragging, moveWindow, showBubble]);
+  }, [isDragging, moveWindow]);
 
   // ══════════════════════════════════════
   // 클릭 반응
   // ══════════════════════════════════════
-  const handleClick = async () => {
-    if (didDrag.current) return;
-    try { await invoke<string>("click_cat"); } catch (e) { console.error(e); }
-
-    clickCount.current += 1;
-    const count = clickCount.current;
-
-    if (clickResetTimer.current) clearTimeout(clickResetTimer.current);
-    clickResetTimer.current = setTimeout(() => { clickCount.current = 0; }, 3000);
-
-    let msg: string;
-    if (count <= 2) {
-      msg = normalMessages[Math.floor(Math.random() * normalMessages.length)];
-      setDisplayEmoji("😻");
-    } else if (count <= 5) {
-      msg = happyMessages[Math.floor(Math.random() * happyMessages.length)];
-      setDisplayEmoji("🥰");
-    } else {
-      msg = annoyedMessages[Math.floor(Math.random() * annoyedMessages.length)];
-      setDisplayEmoji("😾");
+  function touchCat(kind: ClickKind) {
+    invoke<CatReaction>("click_cat", { kind }).catch((e) => console.error(e));
+  }
+
+  // 두 번째 클릭이 오면 더블클릭, 아니면 잠시 뒤 한 번 클릭
+  const handleClick = () => {
+    if (didDrag.current || didLongPress.current) return;
+    if (clickTimer.current) {
+      clearTimeout(clickTimer.current);
+      clickTimer.current = null;
+      touchCat("double");
+      return;
     }
-    showBubble(msg);
-
-    setTimeout(() => {
-      if (state === "idle") setDisplayEmoji(behaviorEmoji[behavior]);
-    }, 2000);
+    clickTimer.current = setTimeout(() => {
+      clickTimer.current = null;
+      touchCat("single");
+    }, DOUBLE_CLICK_MS);
   };
 
+  // 백엔드가 고른 반응 표시
+  useEffect(() => {
+    const unlisten = listen<CatReaction>("cat:reaction", (event) => {
+      const reaction = event.payload;
+      setDisplayEmoji(reaction.emoji);
+      showBubble(reaction.message, reaction.kind === "drag" ? 1500 : 2000);
+      setTimeout(() => {
+        const current = useCatStore.getState().state;
+        if (current === "idle") setDisplayEmoji(behaviorEmoji[behavior]);
+      }, 2000);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [behavior, showBubble]);
+
+  // ══════════════════════════════════════
+  // 꾸미기: 착용 아이템 불러오기 / 갈아입기 반영
+  // ══════════════════════════════════════
+  useEffect(() => {
+    const refresh = () => {
+      invoke<{ items: CosmeticItem[] }>("get_inventory")
+        .then((inventory) => setOutfit(inventory.items.filter((item) => item.equipped)))
+        .catch((e) => console.error(e));
+    };
+    refresh();
+    const unlisten = listen("cat:look-changed", refresh);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const worn = (slot: CosmeticSlot) => outfit.find((item) => item.slot === slot)?.icon;
+
   // ══════════════════════════════════════
   // 렌더
   // ══════════════════════════════════════
   const isFlipped = direction === "left";
+  const hat = worn("hat");
+  const collar = worn("collar");
+  const accessory = worn("accessory");
 
   return (
     <div className="cat-window">
@@ -302,7 +371,7 @@ export function Cat() {
 
       {/* 고양이 (하단) */}
       <div
-        className={`cat cat--${state} cat--${behavior} ${isDragging ? "cat--dragging" : ""}`}
+        className={`cat cat--${state} cat--${behavior} cat--fur-${worn("fur") ?? "orange"} ${isDragging ? "cat--dragging" : ""}`}
         onMouseDown={handleMouseDown}
         onClick={handleClick}
       >
@@ -313,6 +382,10 @@ export function Cat() {
           <span className="cat__emoji">{displayEmoji}</span>
         </div>
 
+        {hat && <div className="cat__hat">{hat}</div>}
+        {collar && <div className="cat__collar">{collar}</div>}
+        {accessory && <div className="cat__accessory">{accessory}</div>}
+
         {behavior === "nap" && state === "idle" && <div className="cat__zzz">z z z</div>}
         {behavior === "lick" && state === "idle" && <div className="cat__effect">✨</div>}
         {state === "celebrating" && <div className="cat__particles">✨🎉✨</div>}
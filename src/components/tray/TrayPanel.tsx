@@ -16,6 +16,9 @@ export function TrayPanel() {
     sleeping: "😴",
     focused: "🔥",
     excited: "💥",
+    proud: "😼",
+    worried: "🙀",
+    bored: "😑",
   };
 
   const formatTime = (minutes: number) => {
@@ -9,7 +9,15 @@ type CatState =
   | "tired"
   | "interaction";
 
-type CatMood = "happy" | "sad" | "sleeping" | "focused" | "excited";
+type CatMood =
+  | "happy"
+  | "sad"
+  | "sleeping"
+  | "focused"
+  | "excited"
+  | "proud"
+  | "worried"
+  | "bored";
 
 interface CatStore {
   // State